@@ -16,6 +16,8 @@ macro_rules! mk_get_language {
         pub fn get_language(lang: &LANG) -> Language {
               match lang {
                   LANG::Java => tree_sitter_java::language(),
+                  LANG::Go => tree_sitter_go::language(),
+                  LANG::Csharp => tree_sitter_c_sharp::language(),
                   LANG::Preproc => tree_sitter_preproc::language(),
                   LANG::Ccomment => tree_sitter_ccomment::language(),
                   LANG::Cpp => tree_sitter_mozcpp::language(),
@@ -0,0 +1,157 @@
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use rust_code_analysis::{get_function_spaces_with_options, FuncSpace, MetricsOptions, LANG};
+
+const EXTENSION: &str = "cbor";
+
+/// An on-disk cache of the metrics of the analyzed files.
+///
+/// The metrics are stored in the `cbor` format and indexed by a hash of the
+/// code, of the language, of the options used to compute them and of the
+/// version of the tool, so a file is analyzed again only when one of them
+/// changes. The metrics computed along with some custom metrics are never
+/// cached, since their values depend on code which is not hashed.
+#[derive(Debug)]
+pub struct Cache {
+    dir: PathBuf,
+    // The entries read or written by this run
+    used: Mutex<HashSet<PathBuf>>,
+    // Used to name the temporary files written by the threads
+    counter: AtomicUsize,
+}
+
+impl Cache {
+    /// Opens a cache directory, creating it if needed
+    pub fn new(dir: PathBuf) -> std::io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            used: Mutex::new(HashSet::new()),
+            counter: AtomicUsize::new(0),
+        })
+    }
+
+    fn key(source: &[u8], language: &LANG, options: &MetricsOptions) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update([0]);
+        hasher.update(language.get_name());
+        hasher.update([0, options.cognitive_increments as u8]);
+        // The selected metrics are hashed by name, whatever their order
+        match &options.metrics {
+            Some(metrics) => {
+                let mut names: Vec<_> = metrics.iter().map(|metric| metric.to_string()).collect();
+                names.sort_unstable();
+                names.dedup();
+                hasher.update([1]);
+                for name in names {
+                    hasher.update(name);
+                    hasher.update([0]);
+                }
+            }
+            None => hasher.update([0]),
+        }
+        hasher.update(source);
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        // Split the entries among some subdirectories,
+        // as git does for its objects
+        self.dir
+            .join(&key[..2])
+            .join(&key[2..])
+            .with_extension(EXTENSION)
+    }
+
+    fn read(&self, key: &str) -> Option<FuncSpace> {
+        let path = self.entry_path(key);
+        let data = fs::read(&path).ok()?;
+        let space = serde_cbor::from_slice(&data).ok()?;
+        self.used.lock().unwrap().insert(path);
+        Some(space)
+    }
+
+    fn write(&self, key: &str, space: &FuncSpace) -> std::io::Result<()> {
+        let path = self.entry_path(key);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write a temporary file and rename it, so that an entry
+        // is never read while it is being written
+        let tmp_path = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            self.counter.fetch_add(1, Ordering::Relaxed)
+        ));
+        let data =
+            serde_cbor::to_vec(space).map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
+        fs::write(&tmp_path, data)?;
+        fs::rename(&tmp_path, &path)?;
+        self.used.lock().unwrap().insert(path);
+        Ok(())
+    }
+
+    /// Returns the metrics of a code, reading them from the cache if they
+    /// have already been computed, otherwise computing and caching them.
+    pub fn get_function_spaces(
+        &self,
+        language: &LANG,
+        source: Vec<u8>,
+        path: &PathBuf,
+        options: &MetricsOptions,
+    ) -> Option<FuncSpace> {
+        if !options.custom.is_empty() {
+            return get_function_spaces_with_options(language, source, path, None, options);
+        }
+
+        let key = Self::key(&source, language, options);
+        if let Some(mut space) = self.read(&key) {
+            // The same code can be contained in several files
+            space.name = path.to_str().map(|name| name.to_string());
+            return Some(space);
+        }
+
+        let space = get_function_spaces_with_options(language, source, path, None, options)?;
+        if let Err(e) = self.write(&key, &space) {
+            eprintln!("Warning: {} caching the metrics of file {:?}", e, path);
+        }
+        Some(space)
+    }
+
+    /// Removes the entries which have not been used by this run,
+    /// returning their number
+    pub fn prune(&self) -> std::io::Result<usize> {
+        let used = self.used.lock().unwrap();
+        let mut removed = 0;
+        for dir in fs::read_dir(&self.dir)? {
+            let dir = dir?.path();
+            if !dir.is_dir() {
+                continue;
+            }
+            for entry in fs::read_dir(&dir)? {
+                let path = entry?.path();
+                if path.extension() == Some(OsStr::new(EXTENSION)) && !used.contains(&path) {
+                    fs::remove_file(&path)?;
+                    removed += 1;
+                }
+            }
+            // Remove the subdirectories which are now empty
+            if fs::read_dir(&dir)?.next().is_none() {
+                fs::remove_dir(&dir)?;
+            }
+        }
+        Ok(removed)
+    }
+}
@@ -0,0 +1,90 @@
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rust_code_analysis::{FuncSpace, LANG};
+
+use crate::thresholds::{Metric, Thresholds};
+
+/// Checks the metrics of the spaces against their thresholds,
+/// printing the violations.
+#[derive(Debug, Default)]
+pub struct Check {
+    thresholds: Thresholds,
+    violations: AtomicUsize,
+}
+
+impl Check {
+    pub fn new(thresholds: Thresholds) -> Self {
+        Self {
+            thresholds,
+            violations: AtomicUsize::new(0),
+        }
+    }
+
+    /// Prints the thresholds exceeded by a space and its subspaces
+    pub fn check_space(
+        &self,
+        space: &FuncSpace,
+        path: &Path,
+        language: &LANG,
+    ) -> std::io::Result<()> {
+        let mut violations = Vec::new();
+        self.collect_violations(space, path, language, &mut violations);
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        self.violations
+            .fetch_add(violations.len(), Ordering::Relaxed);
+
+        write_lines(&violations)
+    }
+
+    fn collect_violations(
+        &self,
+        space: &FuncSpace,
+        path: &Path,
+        language: &LANG,
+        violations: &mut Vec<String>,
+    ) {
+        for violation in self.thresholds.violations(space, language) {
+            let comparison = if violation.metric == Metric::Mi {
+                "is lower than"
+            } else {
+                "exceeds"
+            };
+            violations.push(format!(
+                "{}:{}-{}: {} `{}`: {} {} {} the threshold {}",
+                path.display(),
+                space.start_line,
+                space.end_line,
+                space.kind,
+                space.name.as_deref().unwrap_or(""),
+                violation.metric,
+                violation.value,
+                comparison,
+                violation.threshold
+            ));
+        }
+        for subspace in &space.spaces {
+            self.collect_violations(subspace, path, language, violations);
+        }
+    }
+
+    /// Returns the number of violations found so far
+    pub fn violations(&self) -> usize {
+        self.violations.load(Ordering::Relaxed)
+    }
+}
+
+/// Prints the lines found in a file, locking stdout once,
+/// so that they are not interleaved with the ones of the other files.
+pub fn write_lines(lines: &[String]) -> std::io::Result<()> {
+    let stdout = std::io::stdout();
+    let mut stdout = stdout.lock();
+    for line in lines {
+        writeln!(stdout, "{}", line)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use enum_iterator::IntoEnumIterator;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use rust_code_analysis::{get_from_ext, read_file, LANG};
+
+/// The name of the configuration file of a project.
+pub const CONFIG_FILE: &str = ".rust-code-analysis.toml";
+
+/// Returns the language having the given name or file extension.
+pub fn get_language(language: &str) -> Option<LANG> {
+    match language {
+        "ccomment" => Some(LANG::Ccomment),
+        "preproc" => Some(LANG::Preproc),
+        _ => LANG::into_enum_iter()
+            .find(|lang| lang.get_name() == language)
+            .or_else(|| get_from_ext(language)),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OverrideOptions {
+    path: PathBuf,
+    #[serde(default)]
+    include: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    #[serde(default)]
+    languages: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigOptions {
+    language: Option<String>,
+    jobs: Option<usize>,
+    output_format: Option<String>,
+    preproc: Option<PathBuf>,
+    cache: Option<PathBuf>,
+    thresholds: Option<toml::value::Table>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    languages: BTreeMap<String, String>,
+    overrides: Vec<OverrideOptions>,
+}
+
+/// The files options applied to a directory and its subdirectories.
+#[derive(Debug)]
+struct FilesRules {
+    dir: PathBuf,
+    include: GlobSet,
+    exclude: GlobSet,
+    languages: Vec<(GlobSet, LANG)>,
+}
+
+fn mk_globset(globs: &[String]) -> Result<GlobSet, String> {
+    let mut globset = GlobSetBuilder::new();
+    for glob in globs {
+        globset.add(Glob::new(glob).map_err(|e| e.to_string())?);
+    }
+    globset.build().map_err(|e| e.to_string())
+}
+
+impl FilesRules {
+    fn new(
+        dir: PathBuf,
+        include: &[String],
+        exclude: &[String],
+        languages: &BTreeMap<String, String>,
+    ) -> Result<Self, String> {
+        let languages = languages
+            .iter()
+            .map(|(glob, language)| {
+                let lang = get_language(language)
+                    .ok_or_else(|| format!("{:?} is not a supported language", language))?;
+                Ok((mk_globset(&[glob.to_string()])?, lang))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Self {
+            dir,
+            include: mk_globset(include)?,
+            exclude: mk_globset(exclude)?,
+            languages,
+        })
+    }
+}
+
+/// The configuration of a project, read from a `.rust-code-analysis.toml` file.
+///
+/// The globs are matched against the paths relative to the directory
+/// containing the file, or to the directory of an override.
+#[derive(Debug)]
+pub struct ProjectConfig {
+    pub path: PathBuf,
+    pub language: Option<LANG>,
+    pub jobs: Option<usize>,
+    pub output_format: Option<String>,
+    pub preproc: Option<PathBuf>,
+    pub cache: Option<PathBuf>,
+    pub thresholds: Option<toml::value::Table>,
+    rules: Vec<FilesRules>,
+}
+
+impl ProjectConfig {
+    /// Finds the configuration file walking up from the given path
+    pub fn find(path: &Path) -> Option<PathBuf> {
+        let path = path.canonicalize().ok()?;
+        path.ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|file| file.is_file())
+    }
+
+    /// Reads a configuration file
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let data = read_file(&path.to_path_buf()).map_err(|e| e.to_string())?;
+        let options: ConfigOptions = toml::from_slice(&data).map_err(|e| e.to_string())?;
+        let dir = path
+            .canonicalize()
+            .map_err(|e| e.to_string())?
+            .parent()
+            .map(|dir| dir.to_path_buf())
+            .unwrap_or_default();
+
+        let language = if let Some(language) = &options.language {
+            Some(
+                get_language(language)
+                    .ok_or_else(|| format!("{:?} is not a supported language", language))?,
+            )
+        } else {
+            None
+        };
+
+        let mut rules = vec![FilesRules::new(
+            dir.clone(),
+            &options.include,
+            &options.exclude,
+            &options.languages,
+        )?];
+        for r#override in &options.overrides {
+            rules.push(FilesRules::new(
+                dir.join(&r#override.path),
+                &r#override.include,
+                &r#override.exclude,
+                &r#override.languages,
+            )?);
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            language,
+            jobs: options.jobs,
+            output_format: options.output_format,
+            preproc: options.preproc.map(|preproc| dir.join(preproc)),
+            cache: options.cache.map(|cache| dir.join(cache)),
+            thresholds: options.thresholds,
+            rules,
+        })
+    }
+
+    /// Ignores the top-level include globs
+    pub fn clear_include(&mut self) {
+        self.rules[0].include = GlobSet::empty();
+    }
+
+    /// Ignores the top-level exclude globs
+    pub fn clear_exclude(&mut self) {
+        self.rules[0].exclude = GlobSet::empty();
+    }
+
+    fn matching_rules<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> impl Iterator<Item = (&'a FilesRules, &'a Path)> {
+        self.rules
+            .iter()
+            .filter_map(move |rules| Some((rules, path.strip_prefix(&rules.dir).ok()?)))
+    }
+
+    /// Returns true if a file has to be analyzed
+    pub fn is_included(&self, path: &Path) -> bool {
+        let path = match path.canonicalize() {
+            Ok(path) => path,
+            Err(_) => return true,
+        };
+        let included = self.matching_rules(&path).all(|(rules, path)| {
+            (rules.include.is_empty() || rules.include.is_match(path))
+                && !rules.exclude.is_match(path)
+        });
+        included
+    }
+
+    /// Returns the language of a file, if it is set
+    ///
+    /// The language of an override takes precedence over the top-level one.
+    pub fn get_language(&self, path: &Path) -> Option<LANG> {
+        let path = path.canonicalize().ok()?;
+        let language = self
+            .matching_rules(&path)
+            .filter_map(|(rules, path)| {
+                rules
+                    .languages
+                    .iter()
+                    .find(|(glob, _)| glob.is_match(path))
+                    .map(|(_, lang)| *lang)
+            })
+            .last();
+        language.or(self.language)
+    }
+}
@@ -0,0 +1,184 @@
+use globset::GlobSet;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use rust_code_analysis::{
+    get_function_spaces_with_options, guess_language, read_file_with_eol, Change, FuncSpace,
+    MetricsOptions, SpaceDiff,
+};
+
+use crate::walk::WalkOptions;
+
+/// Reads the metrics of a file produced by the `json` or `cbor` formats.
+fn read_metrics(path: &Path) -> Option<FuncSpace> {
+    let data = std::fs::read(path).ok()?;
+    match path.extension().and_then(OsStr::to_str) {
+        Some("json") => serde_json::from_slice(&data).ok(),
+        Some("cbor") => serde_cbor::from_slice(&data).ok(),
+        _ => None,
+    }
+}
+
+/// Computes the metrics of a source file.
+fn compute_metrics(path: &Path) -> Option<FuncSpace> {
+    let path = path.to_path_buf();
+    let source = read_file_with_eol(&path).ok()??;
+    let language = guess_language(&source, &path).0?;
+    get_function_spaces_with_options(&language, source, &path, None, &MetricsOptions::default())
+}
+
+/// Reads the metrics of all the files contained in a path, which can be
+/// either files produced by the `json` and `cbor` formats or source files.
+///
+/// Each space is returned along with the path of its source file.
+fn read_spaces(
+    root: &Path,
+    include: &GlobSet,
+    exclude: &GlobSet,
+    walk: &WalkOptions,
+) -> Vec<(PathBuf, FuncSpace)> {
+    let mut spaces = Vec::new();
+    for entry in walk.builder(root).build().filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_file()
+            || (!include.is_empty() && !include.is_match(path))
+            || exclude.is_match(path)
+        {
+            continue;
+        }
+        if let Some(space) = read_metrics(path) {
+            let source = PathBuf::from(space.name.clone().unwrap_or_default());
+            spaces.push((source, space));
+        } else if let Some(space) = compute_metrics(path) {
+            spaces.push((path.to_path_buf(), space));
+        }
+    }
+    spaces
+}
+
+fn common_suffix_len(a: &Path, b: &Path) -> usize {
+    a.components()
+        .rev()
+        .zip(b.components().rev())
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+/// Compares the metrics of two versions of a series of files,
+/// returning the files which have changed.
+///
+/// The two versions can be either directories containing the outputs of
+/// the `json` or `cbor` formats or source trees. A file of the new version
+/// is matched with the file of the old version having the same name
+/// and the longest common path.
+pub fn diff_paths(
+    old: &Path,
+    new: &Path,
+    include: &GlobSet,
+    exclude: &GlobSet,
+    walk: &WalkOptions,
+) -> Vec<SpaceDiff> {
+    let old_spaces = read_spaces(old, include, exclude, walk);
+    let new_spaces = read_spaces(new, include, exclude, walk);
+
+    let mut old_names: HashMap<&OsStr, Vec<usize>> = HashMap::new();
+    for (i, (path, _)) in old_spaces.iter().enumerate() {
+        if let Some(name) = path.file_name() {
+            old_names.entry(name).or_default().push(i);
+        }
+    }
+
+    let mut old_matched = vec![false; old_spaces.len()];
+    let mut diffs = Vec::new();
+    for (path, space) in &new_spaces {
+        let old = path
+            .file_name()
+            .and_then(|name| old_names.get(name))
+            .and_then(|candidates| {
+                candidates
+                    .iter()
+                    .filter(|i| !old_matched[**i])
+                    .max_by_key(|i| {
+                        let suffix = common_suffix_len(&old_spaces[**i].0, path);
+                        (suffix, std::cmp::Reverse(**i))
+                    })
+            });
+        if let Some(i) = old {
+            old_matched[*i] = true;
+            diffs.push(SpaceDiff::new(&old_spaces[*i].1, space));
+        } else {
+            diffs.push(SpaceDiff::added(space));
+        }
+    }
+    diffs.extend(
+        old_spaces
+            .iter()
+            .zip(old_matched)
+            .filter(|(_, matched)| !matched)
+            .map(|((_, space), _)| SpaceDiff::removed(space)),
+    );
+    diffs.retain(|diff| diff.is_changed());
+    diffs
+}
+
+fn lines(lines: Option<(usize, usize)>) -> String {
+    lines.map_or(String::new(), |(start, end)| format!("{}-{}", start, end))
+}
+
+/// Writes the changes of a space and of its subspaces, indented by a depth.
+pub fn write_diff<W: Write>(diff: &SpaceDiff, depth: usize, writer: &mut W) -> std::io::Result<()> {
+    let indent = "  ".repeat(depth);
+    let name = diff.name.as_deref().unwrap_or("");
+    let change = match diff.change {
+        Change::Added => "added",
+        Change::Removed => "removed",
+        Change::Renamed => "renamed",
+        Change::Modified => "modified",
+        Change::Unchanged => "unchanged",
+    };
+    let location = match (diff.old_lines, diff.new_lines) {
+        (Some(old), Some(new)) if old != new => {
+            format!("{} -> {}", lines(Some(old)), lines(Some(new)))
+        }
+        (old, new) => lines(new.or(old)),
+    };
+    if let Some(old_name) = &diff.old_name {
+        writeln!(
+            writer,
+            "{}{} {} `{}` -> `{}` ({})",
+            indent, change, diff.kind, old_name, name, location
+        )?;
+    } else {
+        writeln!(
+            writer,
+            "{}{} {} `{}` ({})",
+            indent, change, diff.kind, name, location
+        )?;
+    }
+    for metric in &diff.metrics {
+        writeln!(
+            writer,
+            "{}    {}: {} -> {} ({:+})",
+            indent,
+            metric.name,
+            metric.old,
+            metric.new,
+            metric.delta()
+        )?;
+    }
+    for space in &diff.spaces {
+        write_diff(space, depth + 1, writer)?;
+    }
+    Ok(())
+}
+
+/// Writes the changes as a readable list, one line per space
+/// followed by its changed metrics.
+pub fn write_diffs<W: Write>(diffs: &[SpaceDiff], mut writer: W) -> std::io::Result<()> {
+    for diff in diffs {
+        write_diff(diff, 0, &mut writer)?;
+    }
+    Ok(())
+}
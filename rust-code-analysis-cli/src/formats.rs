@@ -1,28 +1,45 @@
+use std::fmt;
 use std::fs::File;
 use std::io::Write;
 use std::io::{Error, ErrorKind};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use rust_code_analysis::FuncSpace;
+use rust_code_analysis::{CodeMetrics, FuncSpace, LANG};
+
+use crate::sarif::SarifLog;
+use crate::thresholds::Thresholds;
 
 #[derive(Debug, Clone)]
 pub enum Format {
     Cbor,
+    Csv,
     Json,
+    Sarif(Thresholds),
     Toml,
+    Tsv,
     Yaml,
 }
 
 impl Format {
     pub fn all() -> &'static [&'static str] {
-        &["cbor", "json", "toml", "yaml"]
+        &["cbor", "csv", "json", "sarif", "toml", "tsv", "yaml"]
+    }
+
+    /// Returns the delimiter of the tabular formats
+    pub fn delimiter(&self) -> Option<char> {
+        match self {
+            Format::Csv => Some(','),
+            Format::Tsv => Some('\t'),
+            _ => None,
+        }
     }
 
     pub fn dump_formats(
         &self,
         space: &FuncSpace,
         path: &PathBuf,
+        language: &LANG,
         output_path: &Option<PathBuf>,
         pretty: bool,
     ) -> std::io::Result<()> {
@@ -52,12 +69,26 @@ impl Format {
                     writeln!(stdout, "{}", toml_data)
                 }
                 Format::Yaml => writeln!(stdout, "{}", serde_yaml::to_string(&space).unwrap()),
+                Format::Sarif(thresholds) => {
+                    let mut log = SarifLog::new(thresholds.clone());
+                    log.add_space(space, path, language);
+                    log.write(&mut stdout, pretty)?;
+                    writeln!(stdout)
+                }
+                Format::Csv | Format::Tsv => {
+                    let delimiter = self.delimiter().unwrap();
+                    TableWriter::new(delimiter, &space.metrics, Box::new(std::io::stdout()))?
+                        .write_space(space, path)
+                }
             }
         } else {
             let format_ext = match self {
                 Format::Cbor => ".cbor",
+                Format::Csv => ".csv",
                 Format::Json => ".json",
+                Format::Sarif(_) => ".sarif",
                 Format::Toml => ".toml",
+                Format::Tsv => ".tsv",
                 Format::Yaml => ".yml",
             };
 
@@ -108,6 +139,16 @@ impl Format {
                 }
                 Format::Yaml => serde_yaml::to_writer(format_file, &space)
                     .map_err(|e| Error::new(ErrorKind::Other, e.to_string())),
+                Format::Sarif(thresholds) => {
+                    let mut log = SarifLog::new(thresholds.clone());
+                    log.add_space(space, path, language);
+                    log.write(format_file, pretty)
+                }
+                Format::Csv | Format::Tsv => {
+                    let delimiter = self.delimiter().unwrap();
+                    TableWriter::new(delimiter, &space.metrics, Box::new(format_file))?
+                        .write_space(space, path)
+                }
             }
         }
     }
@@ -119,10 +160,123 @@ impl FromStr for Format {
     fn from_str(format: &str) -> Result<Self, Self::Err> {
         match format {
             "cbor" => Ok(Format::Cbor),
+            "csv" => Ok(Format::Csv),
             "json" => Ok(Format::Json),
+            "sarif" => Ok(Format::Sarif(Thresholds::default())),
             "toml" => Ok(Format::Toml),
+            "tsv" => Ok(Format::Tsv),
             "yaml" => Ok(Format::Yaml),
             format => Err(format!("{:?} is not a supported format", format)),
         }
     }
 }
+
+/// Writes the metrics of the spaces as a table, one row per space.
+///
+/// The metric columns are obtained flattening the serialized metrics,
+/// e.g. `cyclomatic.sum`, so non-scalar values are not reported.
+pub struct TableWriter {
+    delimiter: char,
+    columns: Vec<String>,
+    writer: Box<dyn Write + Send>,
+}
+
+impl fmt::Debug for TableWriter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TableWriter")
+            .field("delimiter", &self.delimiter)
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
+
+impl TableWriter {
+    /// Creates a new table, writing its header
+    ///
+    /// The columns are the ones of the given metrics.
+    pub fn new(
+        delimiter: char,
+        metrics: &CodeMetrics,
+        writer: Box<dyn Write + Send>,
+    ) -> std::io::Result<Self> {
+        let mut table = Self {
+            delimiter,
+            columns: metrics
+                .flat_values()
+                .into_iter()
+                .map(|(column, _)| column)
+                .collect(),
+            writer,
+        };
+
+        let mut header: Vec<&str> = vec!["path", "name", "kind", "start_line", "end_line"];
+        header.extend(table.columns.iter().map(|column| column.as_str()));
+        let header = table.format_row(header.into_iter());
+        table.writer.write_all(header.as_bytes())?;
+
+        Ok(table)
+    }
+
+    /// Writes a row for the space and for each of its subspaces
+    pub fn write_space(&mut self, space: &FuncSpace, path: &Path) -> std::io::Result<()> {
+        self.write_rows(space, path, "")?;
+        self.writer.flush()
+    }
+
+    fn write_rows(&mut self, space: &FuncSpace, path: &Path, name: &str) -> std::io::Result<()> {
+        let metrics = space.metrics.flat_values();
+
+        let path_name = path.to_string_lossy();
+        let start_line = space.start_line.to_string();
+        let end_line = space.end_line.to_string();
+        let kind = space.kind.to_string();
+        let values: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| {
+                metrics
+                    .iter()
+                    .find(|(name, _)| name == column)
+                    .filter(|(_, value)| !value.is_nan())
+                    .map_or(String::new(), |(_, value)| value.to_string())
+            })
+            .collect();
+
+        let mut row: Vec<&str> = vec![&path_name, name, &kind, &start_line, &end_line];
+        row.extend(values.iter().map(|value| value.as_str()));
+        let row = self.format_row(row.into_iter());
+        self.writer.write_all(row.as_bytes())?;
+
+        for subspace in &space.spaces {
+            let subspace_name = subspace.name.as_deref().unwrap_or("");
+            let qualified_name = if name.is_empty() {
+                subspace_name.to_string()
+            } else {
+                format!("{}::{}", name, subspace_name)
+            };
+            self.write_rows(subspace, path, &qualified_name)?;
+        }
+
+        Ok(())
+    }
+
+    fn format_row<'a>(&self, fields: impl Iterator<Item = &'a str>) -> String {
+        let mut row = fields
+            .map(|field| self.escape(field))
+            .collect::<Vec<_>>()
+            .join(&self.delimiter.to_string());
+        row.push('\n');
+        row
+    }
+
+    fn escape(&self, field: &str) -> String {
+        if self.delimiter == '\t' {
+            // Tsv fields cannot contain tabs or newlines
+            field.replace(&['\t', '\n', '\r'][..], " ")
+        } else if field.contains(&[',', '"', '\n', '\r'][..]) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use git2::{Delta, Diff, DiffFile, DiffOptions, Oid, Patch, Repository};
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use rust_code_analysis::{
+    get_function_spaces_with_options, guess_language, match_spaces, FuncSpace, MetricsOptions,
+    SpaceDiff, SpaceKind,
+};
+
+use crate::diff::write_diff;
+
+/// A function touched by the changes between two revisions.
+#[derive(Debug, Serialize)]
+pub struct ChangedFunction {
+    /// The path of the file in the new revision,
+    /// or in the old one if the file has been removed
+    pub path: PathBuf,
+    #[serde(flatten)]
+    pub diff: SpaceDiff,
+}
+
+/// The lines changed by the hunks of a file in the two revisions,
+/// as ranges of lines.
+#[derive(Debug, Default)]
+pub struct Hunks {
+    pub old: Vec<(usize, usize)>,
+    pub new: Vec<(usize, usize)>,
+}
+
+fn overlaps(space: Option<&FuncSpace>, ranges: &[(usize, usize)]) -> bool {
+    match space {
+        Some(space) => ranges
+            .iter()
+            .any(|(first, last)| space.start_line <= *last && *first <= space.end_line),
+        None => false,
+    }
+}
+
+impl Hunks {
+    /// Reads the hunks of a file of a diff computed without context lines
+    pub fn new(diff: &Diff, index: usize) -> Result<Self, git2::Error> {
+        let mut hunks = Self::default();
+        if let Some(patch) = Patch::from_diff(diff, index)? {
+            for h in 0..patch.num_hunks() {
+                let (hunk, _) = patch.hunk(h)?;
+                let (old_start, old_lines) = (hunk.old_start() as usize, hunk.old_lines() as usize);
+                let (new_start, new_lines) = (hunk.new_start() as usize, hunk.new_lines() as usize);
+                if old_lines > 0 {
+                    hunks.old.push((old_start, old_start + old_lines - 1));
+                }
+                if new_lines > 0 {
+                    hunks.new.push((new_start, new_start + new_lines - 1));
+                }
+            }
+        }
+        Ok(hunks)
+    }
+
+    fn touch(&self, old: Option<&FuncSpace>, new: Option<&FuncSpace>) -> bool {
+        overlaps(old, &self.old) || overlaps(new, &self.new)
+    }
+}
+
+/// Computes the metrics of a file read from the object database.
+pub fn blob_space(
+    repo: &Repository,
+    id: Oid,
+    path: &Path,
+) -> Result<Option<FuncSpace>, git2::Error> {
+    let blob = repo.find_blob(id)?;
+    let mut source = blob.content().to_vec();
+    // Keep a single end of line, as it is done when reading a file
+    while source.last() == Some(&b'\n') {
+        source.pop();
+    }
+    source.push(b'\n');
+
+    Ok(guess_language(&source, path).0.and_then(|language| {
+        get_function_spaces_with_options(
+            &language,
+            source,
+            &path.to_path_buf(),
+            None,
+            &MetricsOptions::default(),
+        )
+    }))
+}
+
+/// Computes the metrics of a version of a file in a diff,
+/// if the file exists in that version.
+pub fn get_space(repo: &Repository, file: &DiffFile) -> Result<Option<FuncSpace>, git2::Error> {
+    match file.path() {
+        Some(path) if !file.id().is_zero() => blob_space(repo, file.id(), path),
+        _ => Ok(None),
+    }
+}
+
+// The hunks are matched against both versions of the spaces, so the touched
+// functions are reported even when none of their metrics has changed
+fn collect_functions(
+    old: Option<&FuncSpace>,
+    new: Option<&FuncSpace>,
+    hunks: &Hunks,
+    path: &Path,
+    functions: &mut Vec<ChangedFunction>,
+) {
+    let kind = match new.or(old) {
+        Some(space) => space.kind,
+        None => return,
+    };
+    if kind == SpaceKind::Function && hunks.touch(old, new) {
+        if let Some(mut diff) = SpaceDiff::from_match(old, new) {
+            // Nested functions are reported on their own
+            diff.spaces.clear();
+            functions.push(ChangedFunction {
+                path: path.to_path_buf(),
+                diff,
+            });
+        }
+    }
+
+    let old_spaces = old.map_or(&[][..], |space| &space.spaces);
+    let new_spaces = new.map_or(&[][..], |space| &space.spaces);
+    for (old, new) in match_spaces(old_spaces, new_spaces) {
+        collect_functions(old, new, hunks, path, functions);
+    }
+}
+
+/// Compares the functions of two revisions of a git repository,
+/// returning the ones whose lines are touched by the changes.
+///
+/// The files are read from the object database, so the revisions
+/// do not need to be checked out.
+pub fn changed_functions(
+    path: &Path,
+    old_rev: &str,
+    new_rev: &str,
+) -> Result<Vec<ChangedFunction>, git2::Error> {
+    let repo = Repository::discover(path)?;
+    let old_tree = repo.revparse_single(old_rev)?.peel_to_tree()?;
+    let new_tree = repo.revparse_single(new_rev)?.peel_to_tree()?;
+
+    let mut options = DiffOptions::new();
+    options.context_lines(0);
+    let mut diff = repo.diff_tree_to_tree(Some(&old_tree), Some(&new_tree), Some(&mut options))?;
+    diff.find_similar(None)?;
+
+    let mut functions = Vec::new();
+    for (i, delta) in diff.deltas().enumerate() {
+        if !matches!(
+            delta.status(),
+            Delta::Added | Delta::Deleted | Delta::Modified | Delta::Renamed
+        ) {
+            continue;
+        }
+
+        let hunks = Hunks::new(&diff, i)?;
+
+        let old = get_space(&repo, &delta.old_file())?;
+        let new = get_space(&repo, &delta.new_file())?;
+        let path = if new.is_some() {
+            delta.new_file().path()
+        } else {
+            delta.old_file().path()
+        };
+        if let Some(path) = path {
+            collect_functions(old.as_ref(), new.as_ref(), &hunks, path, &mut functions);
+        }
+    }
+    Ok(functions)
+}
+
+/// Writes the changed functions as a readable list, grouped by file.
+pub fn write_functions<W: Write>(
+    functions: &[ChangedFunction],
+    mut writer: W,
+) -> std::io::Result<()> {
+    let mut last_path = None;
+    for function in functions {
+        if last_path != Some(&function.path) {
+            writeln!(writer, "{}", function.path.display())?;
+            last_path = Some(&function.path);
+        }
+        write_diff(&function.diff, 1, &mut writer)?;
+    }
+    Ok(())
+}
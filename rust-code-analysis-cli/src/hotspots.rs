@@ -0,0 +1,260 @@
+use git2::{DiffOptions, Repository, Sort};
+use globset::GlobSet;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rust_code_analysis::{
+    get_function_spaces_with_options, guess_language, read_file_with_eol, FuncSpace,
+    MetricsOptions, SpaceKind,
+};
+
+use crate::git::{get_space, Hunks};
+use crate::walk::WalkOptions;
+
+/// A function ranked by its churn and its complexity.
+#[derive(Debug, Serialize)]
+pub struct Hotspot {
+    /// The path of the file containing the function
+    pub path: PathBuf,
+    /// The qualified name of the function
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    /// The number of commits which changed the function
+    pub commits: usize,
+    /// The number of lines of the function added or removed by the commits
+    pub lines: usize,
+    pub cyclomatic: f64,
+    pub cognitive: f64,
+    /// The number of commits times the sum of the cyclomatic
+    /// and cognitive complexities
+    pub score: f64,
+}
+
+#[derive(Debug, Default)]
+struct Churn {
+    commits: usize,
+    lines: usize,
+}
+
+/// Returns the functions of a space along with their qualified names,
+/// e.g. `Class::method`.
+pub fn functions(space: &FuncSpace) -> Vec<(String, &FuncSpace)> {
+    fn collect<'a>(space: &'a FuncSpace, name: &str, functions: &mut Vec<(String, &'a FuncSpace)>) {
+        for subspace in &space.spaces {
+            let subspace_name = subspace.name.as_deref().unwrap_or("");
+            let qualified_name = if name.is_empty() {
+                subspace_name.to_string()
+            } else {
+                format!("{}::{}", name, subspace_name)
+            };
+            if subspace.kind == SpaceKind::Function {
+                functions.push((qualified_name.clone(), subspace));
+            }
+            collect(subspace, &qualified_name, functions);
+        }
+    }
+
+    let mut functions = Vec::new();
+    collect(space, "", &mut functions);
+    functions
+}
+
+fn overlap(space: &FuncSpace, (first, last): (usize, usize)) -> usize {
+    let start = first.max(space.start_line);
+    let end = last.min(space.end_line);
+    if start <= end {
+        end - start + 1
+    } else {
+        0
+    }
+}
+
+/// Reads the current version of the analyzed files,
+/// indexed by their path relative to the repository.
+fn read_files(
+    paths: &[String],
+    include: &GlobSet,
+    exclude: &GlobSet,
+    walk: &WalkOptions,
+    workdir: &Path,
+) -> HashMap<PathBuf, (PathBuf, FuncSpace)> {
+    let mut files = HashMap::new();
+    for path in paths {
+        for entry in walk.builder(Path::new(path)).build().filter_map(|e| e.ok()) {
+            let path = entry.path().to_path_buf();
+            if !path.is_file()
+                || (!include.is_empty() && !include.is_match(&path))
+                || exclude.is_match(&path)
+            {
+                continue;
+            }
+            let relative = match path.canonicalize() {
+                Ok(full_path) => match full_path.strip_prefix(workdir) {
+                    Ok(relative) => relative.to_path_buf(),
+                    Err(_) => continue,
+                },
+                Err(_) => continue,
+            };
+            let space = read_file_with_eol(&path).ok().flatten().and_then(|source| {
+                let language = guess_language(&source, &path).0?;
+                get_function_spaces_with_options(
+                    &language,
+                    source,
+                    &path,
+                    None,
+                    &MetricsOptions::default(),
+                )
+            });
+            if let Some(space) = space {
+                files.insert(relative, (path, space));
+            }
+        }
+    }
+    files
+}
+
+/// Ranks the functions of the analyzed files by their churn in the
+/// local git history times their complexity.
+///
+/// The commits made in the last `days` days are walked, skipping the merge
+/// commits, and each line changed by a commit is attributed to the functions
+/// containing it in the version of the file before or after the commit.
+/// The functions are identified by their qualified name, so the history
+/// of a renamed function or file is not followed.
+pub fn hotspots(
+    paths: &[String],
+    include: &GlobSet,
+    exclude: &GlobSet,
+    walk: &WalkOptions,
+    days: u64,
+) -> Result<Vec<Hotspot>, git2::Error> {
+    let repo = Repository::discover(&paths[0])?;
+    let workdir = repo
+        .workdir()
+        .and_then(|workdir| workdir.canonicalize().ok())
+        .ok_or_else(|| git2::Error::from_str("The repository has no working directory"))?;
+    let files = read_files(paths, include, exclude, walk, &workdir);
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    // A huge number of days walks the whole history
+    let seconds = days.saturating_mul(24 * 60 * 60).min(i64::MAX as u64) as i64;
+    let since = now.saturating_sub(seconds);
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(Sort::TIME)?;
+    revwalk.push_head()?;
+
+    let mut churns: HashMap<(PathBuf, String), Churn> = HashMap::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.time().seconds() < since {
+            break;
+        }
+        let parent_tree = match commit.parent_count() {
+            0 => None,
+            1 => Some(commit.parent(0)?.tree()?),
+            _ => continue,
+        };
+
+        let mut options = DiffOptions::new();
+        options.context_lines(0);
+        let diff = repo.diff_tree_to_tree(
+            parent_tree.as_ref(),
+            Some(&commit.tree()?),
+            Some(&mut options),
+        )?;
+        for (i, delta) in diff.deltas().enumerate() {
+            let path = match delta.new_file().path() {
+                Some(path) if files.contains_key(path) => path,
+                _ => continue,
+            };
+            let hunks = Hunks::new(&diff, i)?;
+            // A file which has been added or deleted has no old or new version,
+            // even when it exists in the working tree
+            let old = get_space(&repo, &delta.old_file())?;
+            let new = get_space(&repo, &delta.new_file())?;
+
+            // The removed lines are attributed using the old version,
+            // the added ones using the new version
+            let mut lines: HashMap<String, usize> = HashMap::new();
+            for (space, ranges) in &[(old, &hunks.old), (new, &hunks.new)] {
+                let space = match space {
+                    Some(space) => space,
+                    None => continue,
+                };
+                for (name, function) in functions(space) {
+                    let changed: usize = ranges.iter().map(|range| overlap(function, *range)).sum();
+                    if changed > 0 {
+                        *lines.entry(name).or_default() += changed;
+                    }
+                }
+            }
+            for (name, lines) in lines {
+                let churn = churns.entry((path.to_path_buf(), name)).or_default();
+                churn.commits += 1;
+                churn.lines += lines;
+            }
+        }
+    }
+
+    let mut hotspots = Vec::new();
+    for (relative, (path, space)) in &files {
+        for (name, function) in functions(space) {
+            if let Some(churn) = churns.get(&(relative.clone(), name.clone())) {
+                let cyclomatic = function.metrics.cyclomatic.cyclomatic();
+                let cognitive = function.metrics.cognitive.cognitive();
+                hotspots.push(Hotspot {
+                    path: path.clone(),
+                    name,
+                    start_line: function.start_line,
+                    end_line: function.end_line,
+                    commits: churn.commits,
+                    lines: churn.lines,
+                    cyclomatic,
+                    cognitive,
+                    score: churn.commits as f64 * (cyclomatic + cognitive),
+                });
+            }
+        }
+    }
+    hotspots.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.lines.cmp(&a.lines))
+            .then_with(|| a.path.cmp(&b.path))
+            .then(a.start_line.cmp(&b.start_line))
+    });
+    Ok(hotspots)
+}
+
+/// Writes the hotspots as a table, from the highest score to the lowest.
+pub fn write_hotspots<W: Write>(hotspots: &[Hotspot], mut writer: W) -> std::io::Result<()> {
+    writeln!(
+        writer,
+        "{:>8} {:>7} {:>6} {:>10} {:>9}  function",
+        "score", "commits", "lines", "cyclomatic", "cognitive"
+    )?;
+    for hotspot in hotspots {
+        writeln!(
+            writer,
+            "{:>8} {:>7} {:>6} {:>10} {:>9}  {} ({}:{}-{})",
+            hotspot.score,
+            hotspot.commits,
+            hotspot.lines,
+            hotspot.cyclomatic,
+            hotspot.cognitive,
+            hotspot.name,
+            hotspot.path.display(),
+            hotspot.start_line,
+            hotspot.end_line
+        )?;
+    }
+    Ok(())
+}
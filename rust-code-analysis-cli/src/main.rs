@@ -8,34 +8,58 @@ extern crate serde_json;
 extern crate serde_yaml;
 extern crate toml;
 
+mod cache;
+mod check;
+mod config;
+mod diff;
 mod formats;
+mod git;
+mod hotspots;
+mod parse_errors;
+mod sarif;
+mod summary;
+mod thresholds;
+mod walk;
+mod watch;
 
 use clap::{App, Arg};
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::WalkState;
+use serde::Serialize;
 use std::collections::{hash_map, HashMap};
 use std::fmt;
-use std::path::PathBuf;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::{process, thread};
-use walkdir::{DirEntry, WalkDir};
 
-use formats::Format;
+use cache::Cache;
+use check::Check;
+use config::ProjectConfig;
+use formats::{Format, TableWriter};
+use parse_errors::write_parse_errors;
+use sarif::SarifLog;
+use summary::SummaryBuilder;
+use thresholds::Thresholds;
+use walk::WalkOptions;
+use watch::Watch;
 
 // Enums
-use rust_code_analysis::LANG;
+use rust_code_analysis::{Error, LANG};
 
 // Structs
 use rust_code_analysis::{
-    CommentRm, CommentRmCfg, Count, CountCfg, Dump, DumpCfg, Find, FindCfg, Function, FunctionCfg,
-    Metrics, MetricsCfg, PreprocParser, PreprocResults,
+    CallGraph, CodeMetrics, CommentRm, CommentRmCfg, Count, CountCfg, Dump, DumpCfg, Find, FindCfg,
+    FuncSpace, Function, FunctionCfg, Metric, MetricsOptions, PreprocParser, PreprocResults,
 };
 
 // Functions
 use rust_code_analysis::{
-    action, fix_includes, get_from_ext, get_function_spaces, guess_language, preprocess, read_file,
-    read_file_with_eol, write_file,
+    action, dump_root, fix_includes, get_from_ext, get_function_spaces_with_options,
+    guess_language, preprocess, read_file, try_read_file_with_eol, try_read_with_eol, write_file,
 };
 
 // Traits
@@ -50,6 +74,7 @@ struct Config {
     count_filter: Vec<String>,
     function: bool,
     metrics: bool,
+    metrics_options: MetricsOptions,
     output_format: Option<Format>,
     output: Option<PathBuf>,
     pretty: bool,
@@ -58,17 +83,32 @@ struct Config {
     preproc_lock: Option<Arc<Mutex<PreprocResults>>>,
     preproc: Option<Arc<PreprocResults>>,
     count_lock: Option<Arc<Mutex<Count>>>,
+    call_graph_lock: Option<Arc<Mutex<CallGraph>>>,
+    table_lock: Option<Arc<Mutex<TableWriter>>>,
+    sarif_lock: Option<Arc<Mutex<SarifLog>>>,
+    summary_lock: Option<Arc<Mutex<SummaryBuilder>>>,
+    check: Option<Arc<Check>>,
+    parse_errors: bool,
+    cache: Option<Arc<Cache>>,
+    project: Option<ProjectConfig>,
 }
 
 struct JobItem {
     language: Option<LANG>,
     path: PathBuf,
+    // The code read from the standard input
+    source: Option<Vec<u8>>,
+    // Whether the file has been explicitly given on the command line
+    explicit: bool,
     cfg: Arc<Config>,
 }
 
 type JobReceiver = Receiver<Option<JobItem>>;
 type JobSender = Sender<Option<JobItem>>;
 
+/// The path used to read the code from the standard input.
+const STDIN: &str = "-";
+
 fn mk_globset(elems: clap::Values) -> GlobSet {
     let mut globset = GlobSetBuilder::new();
     for e in elems {
@@ -85,39 +125,69 @@ fn mk_globset(elems: clap::Values) -> GlobSet {
     }
 }
 
-fn act_on_file(language: Option<LANG>, path: PathBuf, cfg: &Config) -> std::io::Result<()> {
-    let source = if let Some(source) = read_file_with_eol(&path)? {
-        source
-    } else {
-        return Ok(());
-    };
+fn get_space(
+    language: &LANG,
+    source: Vec<u8>,
+    path: &PathBuf,
+    pr: Option<Arc<PreprocResults>>,
+    cfg: &Config,
+) -> Result<FuncSpace, Error> {
+    // The preprocessor declarations change the metrics of a code,
+    // so they are not cached
+    match &cfg.cache {
+        Some(cache) if pr.is_none() => {
+            cache.get_function_spaces(language, source, path, &cfg.metrics_options)
+        }
+        _ => get_function_spaces_with_options(language, source, path, pr, &cfg.metrics_options),
+    }
+    .ok_or(Error::MetricsNotSupported)
+}
 
-    let language = if let Some(language) = language {
-        language
-    } else if let Some(language) = guess_language(&source, &path).0 {
-        language
-    } else {
-        return Ok(());
+fn act_on_file(
+    language: Option<LANG>,
+    path: PathBuf,
+    source: Option<Vec<u8>>,
+    cfg: &Config,
+) -> Result<(), Error> {
+    let source = match source {
+        Some(source) => source,
+        None => try_read_file_with_eol(&path)?,
     };
+    let language = language
+        .or_else(|| guess_language(&source, &path).0)
+        .ok_or(Error::UnsupportedLanguage)?;
 
     let pr = cfg.preproc.clone();
-    if cfg.dump {
+    let res = if cfg.dump {
         let cfg = DumpCfg {
             line_start: cfg.line_start,
             line_end: cfg.line_end,
         };
         action::<Dump>(&language, source, &path, pr, cfg)
+    } else if let Some(check) = &cfg.check {
+        let space = get_space(&language, source, &path, pr, cfg)?;
+        check.check_space(&space, &path, &language)
+    } else if cfg.parse_errors {
+        let space = get_space(&language, source, &path, pr, cfg)?;
+        write_parse_errors(&space, &path)
     } else if cfg.metrics {
-        if let Some(output_format) = &cfg.output_format {
-            if let Some(space) = get_function_spaces(&language, source, &path, pr) {
-                output_format.dump_formats(&space, &path, &cfg.output, cfg.pretty)
-            } else {
-                Ok(())
-            }
+        if let Some(summary) = &cfg.summary_lock {
+            let space = get_space(&language, source, &path, pr, cfg)?;
+            summary.lock().unwrap().add_space(&space, &path, &language);
+            Ok(())
+        } else if let Some(table) = &cfg.table_lock {
+            let space = get_space(&language, source, &path, pr, cfg)?;
+            table.lock().unwrap().write_space(&space, &path)
+        } else if let Some(sarif) = &cfg.sarif_lock {
+            let space = get_space(&language, source, &path, pr, cfg)?;
+            sarif.lock().unwrap().add_space(&space, &path, &language);
+            Ok(())
+        } else if let Some(output_format) = &cfg.output_format {
+            let space = get_space(&language, source, &path, pr, cfg)?;
+            output_format.dump_formats(&space, &path, &language, &cfg.output, cfg.pretty)
         } else {
-            let cfg = MetricsCfg { path };
-            let path = cfg.path.clone();
-            action::<Metrics>(&language, source, &path, pr, cfg)
+            let space = get_space(&language, source, &path, pr, cfg)?;
+            dump_root(&space)
         }
     } else if cfg.comments {
         let cfg = CommentRmCfg {
@@ -141,6 +211,10 @@ fn act_on_file(language: Option<LANG>, path: PathBuf, cfg: &Config) -> std::io::
             line_end: cfg.line_end,
         };
         action::<Find>(&language, source, &path, pr, cfg)
+    } else if let Some(call_graph) = &cfg.call_graph_lock {
+        let space = get_space(&language, source, &path, pr, cfg)?;
+        call_graph.lock().unwrap().add_file(&path, &space);
+        Ok(())
     } else if cfg.count_lock.is_some() {
         let cfg = CountCfg {
             filters: cfg.count_filter.clone(),
@@ -161,6 +235,21 @@ fn act_on_file(language: Option<LANG>, path: PathBuf, cfg: &Config) -> std::io::
         Ok(())
     } else {
         Ok(())
+    };
+    res.map_err(Error::from)
+}
+
+// Reports why a file cannot be analyzed, skipping silently the files
+// found walking a directory which do not contain any code to analyze,
+// while the reason is always reported for the files explicitly given
+fn report_error(err: Error, path: &Path, explicit: bool) {
+    match err {
+        Error::EmptyCode
+        | Error::NotUtf8
+        | Error::UnsupportedLanguage
+        | Error::MetricsNotSupported
+            if !explicit => {}
+        err => eprintln!("Error: {} for file {:?}", err, path),
     }
 }
 
@@ -172,82 +261,181 @@ fn consumer(receiver: JobReceiver) {
         let job = job.unwrap();
         let path = job.path.clone();
 
-        if let Err(err) = act_on_file(job.language, job.path, &job.cfg) {
-            eprintln!("{:?} for file {:?}", err, path);
+        if let Err(err) = act_on_file(job.language, job.path, job.source, &job.cfg) {
+            report_error(err, &path, job.explicit);
         }
     }
 }
 
-fn send_file(path: PathBuf, cfg: &Arc<Config>, language: Option<LANG>, sender: &JobSender) {
+fn send_file(
+    path: PathBuf,
+    cfg: &Arc<Config>,
+    language: Option<LANG>,
+    explicit: bool,
+    sender: &JobSender,
+) {
     sender
         .send(Some(JobItem {
             language,
             path,
+            source: None,
+            explicit,
             cfg: Arc::clone(cfg),
         }))
         .unwrap();
 }
 
-fn is_hidden(entry: &DirEntry) -> bool {
-    entry
-        .file_name()
-        .to_str()
-        .map(|s| s.starts_with('.'))
-        .unwrap_or(false)
+fn is_included(path: &Path, cfg: &Config, include: &GlobSet, exclude: &GlobSet) -> bool {
+    (include.is_empty() || include.is_match(path))
+        && (exclude.is_empty() || !exclude.is_match(path))
+        && match &cfg.project {
+            Some(project) => project.is_included(path),
+            None => true,
+        }
+}
+
+fn file_language(path: &Path, cfg: &Config, language: Option<LANG>) -> Option<LANG> {
+    // The language given on the command line takes precedence
+    // over the one in the configuration file
+    language.or_else(|| {
+        cfg.project
+            .as_ref()
+            .and_then(|project| project.get_language(path))
+    })
+}
+
+fn send_file_with_language(
+    path: PathBuf,
+    cfg: &Arc<Config>,
+    language: Option<LANG>,
+    explicit: bool,
+    sender: &JobSender,
+) {
+    let language = file_language(&path, cfg, language);
+    send_file(path, cfg, language, explicit, sender);
+}
+
+fn send_stdin(
+    path: PathBuf,
+    cfg: &Arc<Config>,
+    language: Option<LANG>,
+    sender: &JobSender,
+) -> Result<(), Error> {
+    // The code read from the standard input is explicitly given,
+    // so the reason why it cannot be analyzed is always reported
+    let source = try_read_with_eol(&mut std::io::stdin())?;
+    let language = file_language(&path, cfg, language)
+        .or_else(|| guess_language(&source, &path).0)
+        .ok_or(Error::UnsupportedLanguage)?;
+    sender
+        .send(Some(JobItem {
+            language: Some(language),
+            path,
+            source: Some(source),
+            explicit: true,
+            cfg: Arc::clone(cfg),
+        }))
+        .unwrap();
+    Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn explore(
     mut paths: Vec<String>,
     cfg: &Arc<Config>,
     include: GlobSet,
     exclude: GlobSet,
     language: Option<LANG>,
+    walk: WalkOptions,
+    stdin_path: PathBuf,
     sender: &JobSender,
 ) -> HashMap<String, Vec<PathBuf>> {
-    let mut all_files: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    let all_files: Mutex<HashMap<String, Vec<PathBuf>>> = Mutex::new(HashMap::new());
 
     for path in paths.drain(..) {
+        if path == STDIN {
+            if let Err(e) = send_stdin(stdin_path.clone(), cfg, language, sender) {
+                eprintln!("Error: {} for the standard input", e);
+            }
+            continue;
+        }
         let path = PathBuf::from(path);
         if !path.exists() {
             eprintln!("Warning: File doesn't exist: {}", path.to_str().unwrap());
             continue;
         }
         if path.is_dir() {
-            for entry in WalkDir::new(path)
-                .into_iter()
-                .filter_entry(|e| !is_hidden(e))
-            {
-                let entry = entry.unwrap();
-                let path = entry.path().to_path_buf();
-                if (include.is_empty() || include.is_match(&path))
-                    && (exclude.is_empty() || !exclude.is_match(&path))
-                    && path.is_file()
-                {
-                    if cfg.preproc_lock.is_some() {
-                        let file_name = path.file_name().unwrap().to_str().unwrap().to_string();
-                        let path = path.clone();
-                        match all_files.entry(file_name) {
-                            hash_map::Entry::Occupied(l) => {
-                                l.into_mut().push(path);
-                            }
-                            hash_map::Entry::Vacant(p) => {
-                                p.insert(vec![path]);
-                            }
-                        };
+            walk.builder(&path).build_parallel().run(|| {
+                Box::new(|entry| {
+                    let entry = match entry {
+                        Ok(entry) => entry,
+                        Err(e) => {
+                            eprintln!("Warning: {}", e);
+                            return WalkState::Continue;
+                        }
+                    };
+                    let path = entry.into_path();
+                    if path.is_file() && is_included(&path, cfg, &include, &exclude) {
+                        if cfg.preproc_lock.is_some() {
+                            let file_name = path.file_name().unwrap().to_str().unwrap().to_string();
+                            let path = path.clone();
+                            match all_files.lock().unwrap().entry(file_name) {
+                                hash_map::Entry::Occupied(l) => {
+                                    l.into_mut().push(path);
+                                }
+                                hash_map::Entry::Vacant(p) => {
+                                    p.insert(vec![path]);
+                                }
+                            };
+                        }
+
+                        send_file_with_language(path, cfg, language, false, sender);
                     }
+                    WalkState::Continue
+                })
+            });
+        } else if path.is_file() && is_included(&path, cfg, &include, &exclude) {
+            send_file_with_language(path, cfg, language, true, sender);
+        }
+    }
 
-                    send_file(path, &cfg, language, &sender);
-                }
+    let mut all_files = all_files.into_inner().unwrap();
+    // The files are walked in parallel, so sort them to get the same
+    // results at each run
+    for paths in all_files.values_mut() {
+        paths.sort();
+    }
+    all_files
+}
+
+fn create_writer(output: &Option<PathBuf>) -> Box<dyn Write> {
+    if let Some(output) = output {
+        match File::create(output) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(e) => {
+                eprintln!("Error: {} for file {:?}", e, output);
+                process::exit(1);
             }
-        } else if (include.is_empty() || include.is_match(&path))
-            && (exclude.is_empty() || !exclude.is_match(&path))
-            && path.is_file()
-        {
-            send_file(path, &cfg, language, &sender);
         }
+    } else {
+        Box::new(BufWriter::new(std::io::stdout()))
     }
+}
 
-    all_files
+fn print_count(count: &Mutex<Count>) {
+    let mut count = count.lock().unwrap();
+    println!("{}", count);
+    // The watch mode prints the count of each series of changes
+    *count = Count::default();
+}
+
+fn write_json<W: Write, T: Serialize>(writer: W, value: &T, pretty: bool) -> std::io::Result<()> {
+    if pretty {
+        serde_json::to_writer_pretty(writer, value)
+    } else {
+        serde_json::to_writer(writer, value)
+    }
+    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
 }
 
 fn parse_or_exit<T>(s: &str) -> T
@@ -268,13 +456,29 @@ fn main() {
         .about("Analyze source code")
         .arg(
             Arg::with_name("paths")
-                .help("Sets the input files to analyze")
+                .help("Sets the input files to analyze, - to read the standard input")
                 .short("p")
                 .long("paths")
                 .default_value(".")
                 .multiple(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("stdin")
+                .help("Read the code to analyze from the standard input")
+                .long("stdin")
+                .conflicts_with("paths"),
+        )
+        .arg(
+            Arg::with_name("stdin_filename")
+                .help(
+                    "File name of the code read from the standard input, \
+                     used to guess its language and in the outputs",
+                )
+                .long("stdin-filename")
+                .value_name("NAME")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("dump")
                 .help("Specifies the output file")
@@ -311,9 +515,159 @@ fn main() {
         )
         .arg(
             Arg::with_name("metrics")
-                .help("Compute different metrics")
+                .help(
+                    "Compute different metrics, or only the given ones, \
+                     e.g. --metrics=loc,cyclomatic",
+                )
                 .long("metrics")
-                .short("m"),
+                .short("m")
+                .value_name("METRICS")
+                .takes_value(true)
+                .min_values(0)
+                .require_equals(true)
+                .use_delimiter(true),
+        )
+        .arg(
+            Arg::with_name("summary")
+                .help(
+                    "Summarize the metrics of all the analyzed files \
+                     and rank their functions",
+                )
+                .long("summary"),
+        )
+        .arg(
+            Arg::with_name("top")
+                .help("Number of functions ranked by the summary [default: 10]")
+                .long("top")
+                .value_name("N")
+                .requires("summary")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("sort_by")
+                .help(
+                    "Metric ranking the functions of the summary, \
+                     e.g. cognitive.sum [default: cyclomatic.sum]",
+                )
+                .long("sort-by")
+                .value_name("METRIC")
+                .requires("summary")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("cognitive_increments")
+                .help("Explain the increments of the cognitive complexity metric")
+                .long("cognitive-increments"),
+        )
+        .arg(
+            Arg::with_name("call_graph")
+                .help("Build the call graph of the functions and output it in the given format")
+                .long("call-graph")
+                .possible_values(&["json", "dot"])
+                .conflicts_with_all(&[
+                    "cache",
+                    "dump",
+                    "check",
+                    "parse_errors",
+                    "metrics",
+                    "remove_comments",
+                    "function",
+                    "find",
+                ])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("cross_files")
+                .help("Link the calls of the call graph across files")
+                .long("cross-files")
+                .requires("call_graph"),
+        )
+        .arg(
+            Arg::with_name("diff")
+                .help(
+                    "Compare the metrics of two versions, given as source trees \
+                     or as directories of json or cbor metrics",
+                )
+                .long("diff")
+                .value_names(&["OLD", "NEW"])
+                .number_of_values(2)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("git_diff")
+                .help(
+                    "Compare the metrics of the functions changed between two revisions \
+                     of the git repository containing the first path",
+                )
+                .long("git-diff")
+                .value_names(&["OLD", "NEW"])
+                .number_of_values(2)
+                .takes_value(true)
+                .conflicts_with("diff"),
+        )
+        .arg(
+            Arg::with_name("hotspots")
+                .help(
+                    "Rank the functions by their churn in the git history \
+                     times their complexity",
+                )
+                .long("hotspots")
+                .conflicts_with_all(&["diff", "git_diff"]),
+        )
+        .arg(
+            Arg::with_name("since")
+                .help("Number of days of git history walked by the hotspots mode [default: 90]")
+                .long("since")
+                .value_name("DAYS")
+                .requires("hotspots")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("no_ignore")
+                .help(
+                    "Do not respect the .gitignore, .ignore and .rust-code-analysis-ignore \
+                     files when walking the directories",
+                )
+                .long("no-ignore"),
+        )
+        .arg(
+            Arg::with_name("hidden")
+                .help("Walk the hidden files and directories")
+                .long("hidden"),
+        )
+        .arg(
+            Arg::with_name("watch")
+                .help("Keep running and analyze again the files changed in the paths")
+                .long("watch")
+                .conflicts_with_all(&[
+                    "stdin",
+                    "summary",
+                    "in_place",
+                    "check",
+                    "call_graph",
+                    "diff",
+                    "git_diff",
+                    "hotspots",
+                ]),
+        )
+        .arg(
+            Arg::with_name("cache")
+                .help("Directory caching the metrics of the analyzed files")
+                .long("cache")
+                .value_name("DIR")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("no_cache")
+                .help("Do not use the cache directory given in the configuration file")
+                .long("no-cache")
+                .conflicts_with("cache"),
+        )
+        .arg(
+            Arg::with_name("prune_cache")
+                .help("Remove from the cache the files which have not been analyzed")
+                .long("prune-cache")
+                .conflicts_with("no_cache"),
         )
         .arg(
             Arg::with_name("in_place")
@@ -362,6 +716,39 @@ fn main() {
                 .possible_values(Format::all())
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("check")
+                .help("Check the metrics against their thresholds and fail if any is exceeded")
+                .long("check"),
+        )
+        .arg(
+            Arg::with_name("parse_errors")
+                .help(
+                    "List the files and the functions whose parse contains some syntax errors, \
+                     so their metrics could be wrong",
+                )
+                .long("parse-errors")
+                .conflicts_with("check"),
+        )
+        .arg(
+            Arg::with_name("threshold")
+                .help(
+                    "Threshold of a metric for the check mode and the sarif format, \
+                     e.g. cyclomatic=10 or python:class:sloc=500",
+                )
+                .long("threshold")
+                .value_name("[LANGUAGE:][KIND:]METRIC=VALUE")
+                .multiple(true)
+                .number_of_values(1)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("thresholds")
+                .help("Toml file containing the thresholds of the metrics")
+                .long("thresholds")
+                .value_name("FILE")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("pretty")
                 .help("Dump a pretty json file")
@@ -395,6 +782,16 @@ fn main() {
                 .default_value("")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("config")
+                .help(
+                    "Configuration file, by default .rust-code-analysis.toml \
+                     is searched in the directories containing the first path",
+                )
+                .long("config")
+                .value_name("FILE")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("warning")
                 .help("Print the warnings")
@@ -403,11 +800,44 @@ fn main() {
         )
         .get_matches();
 
-    let paths: Vec<_> = matches.values_of("paths").unwrap().collect();
-    let paths: Vec<String> = paths.iter().map(|x| (*x).to_string()).collect();
+    let paths: Vec<String> = if matches.is_present("stdin") {
+        vec![STDIN.to_string()]
+    } else {
+        let paths: Vec<_> = matches.values_of("paths").unwrap().collect();
+        paths.iter().map(|x| (*x).to_string()).collect()
+    };
+    let stdin_path = PathBuf::from(matches.value_of("stdin_filename").unwrap_or(STDIN));
+    // The configuration of the code read from the standard input
+    // is searched from the current directory
+    let config_path = matches.value_of("config").map(PathBuf::from).or_else(|| {
+        if paths[0] == STDIN {
+            ProjectConfig::find(Path::new("."))
+        } else {
+            ProjectConfig::find(Path::new(&paths[0]))
+        }
+    });
+    let mut project = config_path.map(|path| {
+        ProjectConfig::read(&path).unwrap_or_else(|e| {
+            eprintln!("Error: {} for file {:?}", e, path);
+            process::exit(1);
+        })
+    });
+    // The globs passed on the command line replace the ones in the file
+    if let Some(project) = project.as_mut() {
+        if matches.occurrences_of("include") != 0 {
+            project.clear_include();
+        }
+        if matches.occurrences_of("exclude") != 0 {
+            project.clear_exclude();
+        }
+    }
     let dump = matches.is_present("dump");
     let function = matches.is_present("function");
     let in_place = matches.is_present("in_place");
+    if in_place && paths.iter().any(|path| path == STDIN) {
+        eprintln!("Error: The code read from the standard input cannot be modified in place");
+        process::exit(1);
+    }
     let comments = matches.is_present("remove_comments");
     let find = matches.value_of("find").unwrap();
     let find_filter: Vec<_> = find
@@ -426,11 +856,32 @@ fn main() {
     } else {
         None
     };
-    let metrics = matches.is_present("metrics");
+    let summary = matches.is_present("summary");
+    let metrics = matches.is_present("metrics") || summary;
+    let call_graph_format = matches.value_of("call_graph");
+    let call_graph_lock = if call_graph_format.is_some() {
+        Some(Arc::new(Mutex::new(CallGraph::default())))
+    } else {
+        None
+    };
+    let metrics_options = MetricsOptions {
+        cognitive_increments: matches.is_present("cognitive_increments"),
+        metrics: matches
+            .values_of("metrics")
+            .map(|values| values.map(parse_or_exit::<Metric>).collect::<Vec<_>>())
+            .filter(|metrics| !metrics.is_empty()),
+        ..MetricsOptions::default()
+    };
     let typ = matches.value_of("language_type").unwrap();
     let preproc_value = matches.value_of("preproc").unwrap();
-    let (preproc_lock, preproc) = if !preproc_value.is_empty() {
-        let path = PathBuf::from(preproc_value);
+    let preproc_path = if !preproc_value.is_empty() {
+        Some(PathBuf::from(preproc_value))
+    } else if matches.occurrences_of("preproc") == 0 {
+        project.as_ref().and_then(|project| project.preproc.clone())
+    } else {
+        None
+    };
+    let (preproc_lock, preproc) = if let Some(path) = preproc_path {
         let data = read_file(&path).unwrap();
         eprintln!("Load preproc data");
         let x = (
@@ -447,16 +898,196 @@ fn main() {
         (None, None)
     };
 
+    let mut thresholds = Thresholds::default();
+    if let Some(project) = &project {
+        if let Some(Err(e)) = project
+            .thresholds
+            .as_ref()
+            .map(|table| thresholds.parse_toml(table))
+        {
+            eprintln!("Error: {} for file {:?}", e, project.path);
+            process::exit(1);
+        }
+    }
+    if let Some(path) = matches.value_of("thresholds") {
+        let table = read_file(&PathBuf::from(path))
+            .map_err(|e| e.to_string())
+            .and_then(|data| toml::from_slice(&data).map_err(|e| e.to_string()))
+            .and_then(|table| thresholds.parse_toml(&table));
+        if let Err(e) = table {
+            eprintln!("Error: {} for file {:?}", e, path);
+            process::exit(1);
+        }
+    }
+    // The thresholds passed on the command line override the ones in the file
+    for threshold in matches.values_of("threshold").into_iter().flatten() {
+        thresholds.parse(threshold).unwrap_or_else(|e| {
+            eprintln!("Error:\n{}", e);
+            process::exit(1);
+        });
+    }
     let output_format = matches
         .value_of("output_format")
-        .map(parse_or_exit::<Format>);
+        .or_else(|| {
+            project
+                .as_ref()
+                .and_then(|project| project.output_format.as_deref())
+        })
+        .map(parse_or_exit::<Format>)
+        .map(|format| match format {
+            Format::Sarif(_) => Format::Sarif(thresholds.clone()),
+            format => format,
+        });
     let pretty = matches.is_present("pretty");
+    let walk = WalkOptions {
+        no_ignore: matches.is_present("no_ignore"),
+        hidden: matches.is_present("hidden"),
+        threads: 0,
+    };
     let output = matches.value_of("output").map(PathBuf::from);
+    if matches.is_present("diff")
+        || matches.is_present("git_diff")
+        || matches.is_present("hotspots")
+    {
+        let json = match output_format {
+            Some(Format::Json) => true,
+            None => false,
+            Some(_) => {
+                eprintln!("Error: The differences and the hotspots can only be output as json");
+                process::exit(1);
+            }
+        };
+        let writer = create_writer(&output);
+
+        let res = if let Some(mut versions) = matches.values_of("diff") {
+            let old = PathBuf::from(versions.next().unwrap());
+            let new = PathBuf::from(versions.next().unwrap());
+            let include = mk_globset(matches.values_of("include").unwrap());
+            let exclude = mk_globset(matches.values_of("exclude").unwrap());
+            let diffs = diff::diff_paths(&old, &new, &include, &exclude, &walk);
+            if json {
+                write_json(writer, &diffs, pretty)
+            } else {
+                diff::write_diffs(&diffs, writer)
+            }
+        } else if matches.is_present("hotspots") {
+            let days = parse_or_exit::<u64>(matches.value_of("since").unwrap_or("90"));
+            let include = mk_globset(matches.values_of("include").unwrap());
+            let exclude = mk_globset(matches.values_of("exclude").unwrap());
+            match hotspots::hotspots(&paths, &include, &exclude, &walk, days) {
+                Ok(hotspots) if json => write_json(writer, &hotspots, pretty),
+                Ok(hotspots) => hotspots::write_hotspots(&hotspots, writer),
+                Err(e) => Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.message().to_string(),
+                )),
+            }
+        } else {
+            let mut revisions = matches.values_of("git_diff").unwrap();
+            let old = revisions.next().unwrap();
+            let new = revisions.next().unwrap();
+            match git::changed_functions(Path::new(&paths[0]), old, new) {
+                Ok(functions) if json => write_json(writer, &functions, pretty),
+                Ok(functions) => git::write_functions(&functions, writer),
+                Err(e) => Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.message().to_string(),
+                )),
+            }
+        };
+        if let Err(e) = res {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
     let output_is_dir = output.as_ref().map(|p| p.is_dir()).unwrap_or(false);
-    if metrics && output.is_some() && !output_is_dir {
+    let delimiter = output_format.as_ref().and_then(|format| format.delimiter());
+    let is_sarif = matches!(output_format, Some(Format::Sarif(_)));
+    if metrics_options.metrics.is_some() && (is_sarif || matches.is_present("check")) {
+        eprintln!("Error: The thresholds need all the metrics, so they cannot be selected");
+        process::exit(1);
+    }
+    let selected_metrics = metrics_options.metrics.as_deref().unwrap_or(Metric::all());
+    if metrics && output.is_some() && !output_is_dir && delimiter.is_none() && !is_sarif && !summary
+    {
         eprintln!("Error: The output parameter must be a directory");
         process::exit(1);
     }
+    let summary_json = summary && output_format.is_some();
+    let summary_lock = if summary {
+        if !matches!(output_format, None | Some(Format::Json)) {
+            eprintln!("Error: The summary can only be output as json");
+            process::exit(1);
+        }
+        let metric = matches.value_of("sort_by").unwrap_or("cyclomatic.sum");
+        if let Err(e) = summary::check_metric(metric, selected_metrics) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        let top = parse_or_exit::<usize>(matches.value_of("top").unwrap_or("10"));
+        Some(Arc::new(Mutex::new(SummaryBuilder::new(metric, top))))
+    } else {
+        None
+    };
+    // All the rows of a table are written in a single file,
+    // unless an output directory is given
+    let table_lock = match delimiter {
+        Some(delimiter) if metrics && !output_is_dir => {
+            let writer: Box<dyn Write + Send> = if let Some(output) = &output {
+                match File::create(output) {
+                    Ok(file) => Box::new(BufWriter::new(file)),
+                    Err(e) => {
+                        eprintln!("Error: {} for file {:?}", e, output);
+                        process::exit(1);
+                    }
+                }
+            } else {
+                Box::new(BufWriter::new(std::io::stdout()))
+            };
+            let columns = CodeMetrics::with_metrics(selected_metrics);
+            let table = TableWriter::new(delimiter, &columns, writer).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            });
+            Some(Arc::new(Mutex::new(table)))
+        }
+        _ => None,
+    };
+    // All the results of a sarif log are written in a single file,
+    // unless an output directory is given
+    let sarif_lock = if is_sarif && metrics && !output_is_dir {
+        Some(Arc::new(Mutex::new(SarifLog::new(thresholds.clone()))))
+    } else {
+        None
+    };
+    let parse_errors = matches.is_present("parse_errors");
+    let check = if matches.is_present("check") {
+        Some(Arc::new(Check::new(thresholds)))
+    } else {
+        None
+    };
+    let cache_dir = if matches.is_present("no_cache") {
+        None
+    } else if let Some(cache_dir) = matches.value_of("cache") {
+        Some(PathBuf::from(cache_dir))
+    } else {
+        project.as_ref().and_then(|project| project.cache.clone())
+    };
+    let cache = cache_dir.map(|cache_dir| {
+        Arc::new(Cache::new(cache_dir.clone()).unwrap_or_else(|e| {
+            eprintln!("Error: {} for cache directory {:?}", e, cache_dir);
+            process::exit(1);
+        }))
+    });
+    if matches.is_present("prune_cache") && cache.is_none() {
+        eprintln!("Error: No cache directory to prune");
+        process::exit(1);
+    }
+    if matches.is_present("watch") && (sarif_lock.is_some() || preproc_lock.is_some()) {
+        eprintln!("Error: The sarif log and the preprocessor data cannot be watched");
+        process::exit(1);
+    }
     let language = if preproc_lock.is_some() {
         Some(LANG::Preproc)
     } else if typ.is_empty() {
@@ -471,6 +1102,8 @@ fn main() {
 
     let num_jobs = if let Ok(num_jobs) = matches.value_of("num_jobs").unwrap().parse::<usize>() {
         std::cmp::max(2, num_jobs) - 1
+    } else if let Some(num_jobs) = project.as_ref().and_then(|project| project.jobs) {
+        std::cmp::max(2, num_jobs) - 1
     } else {
         std::cmp::max(2, num_cpus::get()) - 1
     };
@@ -494,6 +1127,7 @@ fn main() {
         count_filter,
         function,
         metrics,
+        metrics_options,
         output_format,
         pretty,
         output: output.clone(),
@@ -502,18 +1136,43 @@ fn main() {
         preproc_lock: preproc_lock.clone(),
         preproc,
         count_lock: count_lock.clone(),
+        call_graph_lock: call_graph_lock.clone(),
+        table_lock,
+        sarif_lock: sarif_lock.clone(),
+        summary_lock: summary_lock.clone(),
+        check: check.clone(),
+        parse_errors,
+        cache: cache.clone(),
+        project,
     });
 
+    // The paths are walked before the first analysis,
+    // so the changes made meanwhile are not missed
+    let watch = if matches.is_present("watch") {
+        Some((Watch::new(&paths, walk), Arc::clone(&cfg)))
+    } else {
+        None
+    };
+
     let (sender, receiver) = unbounded();
 
     let producer = {
         let sender = sender.clone();
         let include = mk_globset(matches.values_of("include").unwrap());
         let exclude = mk_globset(matches.values_of("exclude").unwrap());
+        // The directories are walked using as many threads as the consumers
+        let walk = WalkOptions {
+            threads: num_jobs,
+            ..walk
+        };
 
         thread::Builder::new()
             .name(String::from("Producer"))
-            .spawn(move || explore(paths, &cfg, include, exclude, language, &sender))
+            .spawn(move || {
+                explore(
+                    paths, &cfg, include, exclude, language, walk, stdin_path, &sender,
+                )
+            })
             .unwrap()
     };
 
@@ -548,9 +1207,70 @@ fn main() {
         }
     }
 
-    if let Some(count) = count_lock {
-        let count = Arc::try_unwrap(count).unwrap().into_inner().unwrap();
-        println!("{}", count);
+    if let Some(count) = &count_lock {
+        print_count(count);
+    }
+
+    if let Some(cache) = cache.as_ref().filter(|_| matches.is_present("prune_cache")) {
+        if let Err(e) = cache.prune() {
+            eprintln!("Error: {} pruning the cache", e);
+            process::exit(1);
+        }
+    }
+
+    if let Some(sarif) = sarif_lock {
+        let sarif = Arc::try_unwrap(sarif).unwrap().into_inner().unwrap();
+        let res = if let Some(output_path) = &output {
+            File::create(output_path).and_then(|file| sarif.write(BufWriter::new(file), pretty))
+        } else {
+            let stdout = std::io::stdout();
+            let mut stdout = stdout.lock();
+            sarif
+                .write(&mut stdout, pretty)
+                .and_then(|_| writeln!(stdout))
+        };
+        if let Err(e) = res {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+
+    if let Some(summary) = summary_lock {
+        let summary = Arc::try_unwrap(summary)
+            .unwrap()
+            .into_inner()
+            .unwrap()
+            .build();
+        let writer = create_writer(&output);
+        let res = if summary_json {
+            write_json(writer, &summary, pretty)
+        } else {
+            summary::write_summary(&summary, writer)
+        };
+        if let Err(e) = res {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+
+    if let Some(call_graph) = call_graph_lock {
+        let mut call_graph = Arc::try_unwrap(call_graph).unwrap().into_inner().unwrap();
+        if matches.is_present("cross_files") {
+            call_graph.link_files();
+        }
+
+        let data = if call_graph_format == Some("dot") {
+            call_graph.dot()
+        } else if pretty {
+            serde_json::to_string_pretty(&call_graph).unwrap()
+        } else {
+            serde_json::to_string(&call_graph).unwrap()
+        };
+        if let Some(output_path) = &output {
+            write_file(output_path, data.as_bytes()).unwrap();
+        } else {
+            println!("{}", data);
+        }
     }
 
     if let Some(preproc) = preproc_lock {
@@ -564,4 +1284,35 @@ fn main() {
             println!("{}", data);
         }
     }
+
+    if let Some(check) = check {
+        let violations = check.violations();
+        if violations != 0 {
+            eprintln!("Error: {} thresholds exceeded", violations);
+            process::exit(1);
+        }
+    }
+
+    if let Some((mut watch, cfg)) = watch {
+        let include = mk_globset(matches.values_of("include").unwrap());
+        let exclude = mk_globset(matches.values_of("exclude").unwrap());
+        let res = watch.run(|paths| {
+            for path in paths {
+                if !is_included(&path, &cfg, &include, &exclude) {
+                    continue;
+                }
+                let language = file_language(&path, &cfg, language);
+                if let Err(err) = act_on_file(language, path.clone(), None, &cfg) {
+                    report_error(err, &path, false);
+                }
+            }
+            if let Some(count) = &count_lock {
+                print_count(count);
+            }
+        });
+        if let Err(e) = res {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use rust_code_analysis::FuncSpace;
+
+use crate::check::write_lines;
+
+/// Prints the spaces of a file whose parse contains some syntax errors,
+/// along with the locations of the errors, so that it is possible to tell
+/// which metric values could be wrong.
+pub fn write_parse_errors(space: &FuncSpace, path: &Path) -> std::io::Result<()> {
+    if space.parse_errors == 0 {
+        return Ok(());
+    }
+
+    let mut lines = Vec::new();
+    collect_parse_errors(space, path, &mut lines);
+
+    write_lines(&lines)
+}
+
+fn collect_parse_errors(space: &FuncSpace, path: &Path, lines: &mut Vec<String>) {
+    lines.push(format!(
+        "{}:{}-{}: {} `{}`: {} parse error{}",
+        path.display(),
+        space.start_line,
+        space.end_line,
+        space.kind,
+        space.name.as_deref().unwrap_or(""),
+        space.parse_errors,
+        if space.parse_errors == 1 { "" } else { "s" }
+    ));
+    for error in &space.errors {
+        lines.push(format!("    {}", error));
+    }
+    for subspace in &space.spaces {
+        if subspace.parse_errors > 0 {
+            collect_parse_errors(subspace, path, lines);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use serde_json::{json, Value};
+use std::io::Write;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use rust_code_analysis::{FuncSpace, LANG};
+
+use crate::thresholds::{Metric, Thresholds};
+
+/// A `SARIF 2.1.0` log containing the functions which exceed
+/// the thresholds of their metrics.
+#[derive(Debug, Default)]
+pub struct SarifLog {
+    thresholds: Thresholds,
+    results: Vec<Value>,
+}
+
+impl SarifLog {
+    pub fn new(thresholds: Thresholds) -> Self {
+        Self {
+            thresholds,
+            results: Vec::new(),
+        }
+    }
+
+    /// Adds a result for each threshold exceeded by a space and its subspaces
+    pub fn add_space(&mut self, space: &FuncSpace, path: &Path, language: &LANG) {
+        let name = space.name.as_deref().unwrap_or("");
+        for violation in self.thresholds.violations(space, language) {
+            let bound = if violation.metric == Metric::Mi {
+                "minimum"
+            } else {
+                "maximum"
+            };
+            let message = format!(
+                "{} of {} `{}` is {}, the {} allowed is {}",
+                violation.metric.description(),
+                space.kind,
+                name,
+                violation.value,
+                bound,
+                violation.threshold
+            );
+            self.results.push(json!({
+                "ruleId": violation.metric.name(),
+                "level": "warning",
+                "message": { "text": message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": path.to_string_lossy().replace('\\', "/")
+                        },
+                        "region": {
+                            "startLine": space.start_line,
+                            "endLine": space.end_line
+                        }
+                    }
+                }]
+            }));
+        }
+        for subspace in &space.spaces {
+            self.add_space(subspace, path, language);
+        }
+    }
+
+    /// Writes the log
+    pub fn write<W: Write>(&self, writer: W, pretty: bool) -> std::io::Result<()> {
+        let rules: Vec<Value> = Metric::all()
+            .iter()
+            .map(|metric| {
+                json!({
+                    "id": metric.name(),
+                    "shortDescription": { "text": metric.description() }
+                })
+            })
+            .collect();
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "rust-code-analysis",
+                        "version": crate_version!(),
+                        "informationUri": "https://github.com/mozilla/rust-code-analysis",
+                        "rules": rules
+                    }
+                },
+                "results": self.results
+            }]
+        });
+        if pretty {
+            serde_json::to_writer_pretty(writer, &log)
+        } else {
+            serde_json::to_writer(writer, &log)
+        }
+        .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))
+    }
+}
@@ -0,0 +1,380 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use rust_code_analysis::{CodeMetrics, FuncSpace, Metric, LANG};
+
+use crate::hotspots::functions;
+
+/// The metrics summed by the summary, named as in their serialization.
+const TOTALS: &[&str] = &[
+    "loc.sloc",
+    "loc.ploc",
+    "loc.lloc",
+    "loc.cloc",
+    "loc.blank",
+    "nom.functions",
+    "nom.closures",
+    "cyclomatic.sum",
+    "cognitive.sum",
+    "nargs.sum",
+    "nexits.sum",
+];
+
+/// The percentiles of the distribution of a metric.
+const PERCENTILES: &[usize] = &[50, 75, 90, 95, 99];
+
+/// The number of buckets of the histogram of a metric.
+const BUCKETS: usize = 10;
+
+/// Returns the value of a metric named as in its serialization,
+/// e.g. `cyclomatic.sum`, if it is defined.
+fn metric_value(metrics: &[(String, f64)], name: &str) -> Option<f64> {
+    metrics
+        .iter()
+        .find(|(metric, _)| metric == name)
+        .map(|(_, value)| *value)
+        .filter(|value| !value.is_nan())
+}
+
+/// Checks that a metric can be used to rank the functions,
+/// when the given metrics are computed.
+pub fn check_metric(name: &str, selected: &[Metric]) -> Result<(), String> {
+    let metrics = CodeMetrics::with_metrics(selected).flat_values();
+    if metrics.iter().any(|(metric, _)| metric == name) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{:?} is not a computed metric, e.g. cyclomatic.sum",
+            name
+        ))
+    }
+}
+
+/// The totals and the averages per file of the metrics of a group of files.
+#[derive(Debug, Default, Serialize)]
+pub struct GroupSummary {
+    pub files: usize,
+    pub totals: BTreeMap<String, f64>,
+    pub averages: BTreeMap<String, f64>,
+}
+
+impl GroupSummary {
+    fn add(&mut self, metrics: &[(String, f64)]) {
+        self.files += 1;
+        for name in TOTALS {
+            if let Some(value) = metric_value(metrics, name) {
+                *self.totals.entry(name.to_string()).or_default() += value;
+            }
+        }
+    }
+
+    fn finalize(&mut self) {
+        let files = self.files as f64;
+        self.averages = self
+            .totals
+            .iter()
+            .map(|(name, total)| (name.clone(), total / files))
+            .collect();
+    }
+}
+
+/// The value of the ranking metric for a function.
+#[derive(Debug, Serialize)]
+pub struct FunctionValue {
+    pub path: PathBuf,
+    /// The qualified name of the function
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub value: f64,
+}
+
+/// A bucket of the histogram of a metric, containing the values
+/// in `[from, to)`, or in `[from, to]` for the last one.
+#[derive(Debug, Serialize)]
+pub struct Bucket {
+    pub from: f64,
+    pub to: f64,
+    pub count: usize,
+}
+
+/// The distribution of the values of a metric among the functions.
+#[derive(Debug, Default, Serialize)]
+pub struct Distribution {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// The values below which a percentage of the values falls,
+    /// using the nearest-rank method
+    pub percentiles: BTreeMap<String, f64>,
+    pub histogram: Vec<Bucket>,
+}
+
+impl Distribution {
+    fn new(mut values: Vec<f64>) -> Self {
+        if values.is_empty() {
+            return Self::default();
+        }
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let count = values.len();
+        let (min, max) = (values[0], values[count - 1]);
+
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|p| {
+                let rank = (*p as f64 / 100. * count as f64).ceil() as usize;
+                (format!("p{}", p), values[rank.max(1) - 1])
+            })
+            .collect();
+
+        let buckets = if max > min { BUCKETS } else { 1 };
+        let width = (max - min) / buckets as f64;
+        let mut histogram: Vec<_> = (0..buckets)
+            .map(|i| Bucket {
+                from: min + width * i as f64,
+                to: if i + 1 == buckets {
+                    max
+                } else {
+                    min + width * (i + 1) as f64
+                },
+                count: 0,
+            })
+            .collect();
+        for value in &values {
+            let i = if width > 0. {
+                (((value - min) / width) as usize).min(buckets - 1)
+            } else {
+                0
+            };
+            histogram[i].count += 1;
+        }
+
+        Self {
+            count,
+            min,
+            max,
+            mean: values.iter().sum::<f64>() / count as f64,
+            percentiles,
+            histogram,
+        }
+    }
+}
+
+/// A summary of the metrics of all the analyzed files.
+#[derive(Debug, Default, Serialize)]
+pub struct Summary {
+    pub files: usize,
+    pub languages: BTreeMap<String, GroupSummary>,
+    /// The files grouped by the directory containing them
+    pub directories: BTreeMap<PathBuf, GroupSummary>,
+    /// The metric used to rank the functions
+    pub metric: String,
+    /// The functions with the highest values of the metric
+    pub top: Vec<FunctionValue>,
+    pub distribution: Distribution,
+    /// The files whose parse contains some syntax errors,
+    /// so their metrics could be wrong
+    pub parse_errors: Vec<PathBuf>,
+}
+
+/// Gathers the metrics of the analyzed files to summarize them.
+#[derive(Debug)]
+pub struct SummaryBuilder {
+    summary: Summary,
+    top: usize,
+    functions: Vec<FunctionValue>,
+}
+
+impl SummaryBuilder {
+    pub fn new(metric: &str, top: usize) -> Self {
+        Self {
+            summary: Summary {
+                metric: metric.to_string(),
+                ..Summary::default()
+            },
+            top,
+            functions: Vec::new(),
+        }
+    }
+
+    /// Adds the metrics of a file
+    pub fn add_space(&mut self, space: &FuncSpace, path: &Path, language: &LANG) {
+        let summary = &mut self.summary;
+        summary.files += 1;
+        if space.parse_errors > 0 {
+            summary.parse_errors.push(path.to_path_buf());
+        }
+
+        let metrics = space.metrics.flat_values();
+        summary
+            .languages
+            .entry(language.get_name().to_string())
+            .or_default()
+            .add(&metrics);
+        summary
+            .directories
+            .entry(path.parent().map(Path::to_path_buf).unwrap_or_default())
+            .or_default()
+            .add(&metrics);
+
+        for (name, function) in functions(space) {
+            let metrics = function.metrics.flat_values();
+            if let Some(value) = metric_value(&metrics, &summary.metric) {
+                self.functions.push(FunctionValue {
+                    path: path.to_path_buf(),
+                    name,
+                    start_line: function.start_line,
+                    end_line: function.end_line,
+                    value,
+                });
+            }
+        }
+    }
+
+    /// Computes the averages, the distribution and the ranking of the functions
+    pub fn build(mut self) -> Summary {
+        let mut summary = self.summary;
+        for group in summary
+            .languages
+            .values_mut()
+            .chain(summary.directories.values_mut())
+        {
+            group.finalize();
+        }
+        summary.parse_errors.sort();
+
+        summary.distribution = Distribution::new(
+            self.functions
+                .iter()
+                .map(|function| function.value)
+                .collect(),
+        );
+        self.functions.sort_by(|a, b| {
+            b.value
+                .partial_cmp(&a.value)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.path.cmp(&b.path))
+                .then(a.start_line.cmp(&b.start_line))
+        });
+        self.functions.truncate(self.top);
+        summary.top = self.functions;
+        summary
+    }
+}
+
+fn write_groups<'a, W: Write>(
+    title: &str,
+    groups: impl Iterator<Item = (String, &'a GroupSummary)>,
+    writer: &mut W,
+) -> std::io::Result<()> {
+    writeln!(
+        writer,
+        "{:<30} {:>6} {:>9} {:>9} {:>9} {:>10} {:>9} {:>10}",
+        title, "files", "sloc", "ploc", "functions", "cyclomatic", "cognitive", "avg cyclo"
+    )?;
+    for (key, group) in groups {
+        // The metrics which have not been computed are missing
+        let total = |name: &str| {
+            group
+                .totals
+                .get(name)
+                .map_or_else(|| "-".to_string(), |total| total.to_string())
+        };
+        let average = group
+            .averages
+            .get("cyclomatic.sum")
+            .map_or_else(|| "-".to_string(), |average| format!("{:.2}", average));
+        writeln!(
+            writer,
+            "{:<30} {:>6} {:>9} {:>9} {:>9} {:>10} {:>9} {:>10}",
+            key,
+            group.files,
+            total("loc.sloc"),
+            total("loc.ploc"),
+            total("nom.functions"),
+            total("cyclomatic.sum"),
+            total("cognitive.sum"),
+            average
+        )?;
+    }
+    writeln!(writer)
+}
+
+/// Writes a summary as a series of tables.
+pub fn write_summary<W: Write>(summary: &Summary, mut writer: W) -> std::io::Result<()> {
+    writeln!(writer, "{} files analyzed\n", summary.files)?;
+    write_groups(
+        "language",
+        summary
+            .languages
+            .iter()
+            .map(|(language, group)| (language.clone(), group)),
+        &mut writer,
+    )?;
+    write_groups(
+        "directory",
+        summary
+            .directories
+            .iter()
+            .map(|(dir, group)| (dir.display().to_string(), group)),
+        &mut writer,
+    )?;
+
+    writeln!(
+        writer,
+        "Top {} functions by {}",
+        summary.top.len(),
+        summary.metric
+    )?;
+    for function in &summary.top {
+        writeln!(
+            writer,
+            "{:>10}  {} ({}:{}-{})",
+            function.value,
+            function.name,
+            function.path.display(),
+            function.start_line,
+            function.end_line
+        )?;
+    }
+    writeln!(writer)?;
+
+    let distribution = &summary.distribution;
+    writeln!(
+        writer,
+        "Distribution of {} among {} functions: min {}, mean {:.2}, max {}",
+        summary.metric, distribution.count, distribution.min, distribution.mean, distribution.max
+    )?;
+    let percentiles: Vec<_> = PERCENTILES
+        .iter()
+        .filter_map(|p| {
+            let key = format!("p{}", p);
+            distribution
+                .percentiles
+                .get(&key)
+                .map(|value| format!("{} {}", key, value))
+        })
+        .collect();
+    writeln!(writer, "{}", percentiles.join(", "))?;
+    for bucket in &distribution.histogram {
+        writeln!(
+            writer,
+            "{:>10.2} - {:<10.2} {:>7} {}",
+            bucket.from,
+            bucket.to,
+            bucket.count,
+            "#".repeat(bucket.count * 50 / distribution.count.max(1))
+        )?;
+    }
+
+    if !summary.parse_errors.is_empty() {
+        writeln!(writer, "\nFiles with parse errors")?;
+        for path in &summary.parse_errors {
+            writeln!(writer, "  {}", path.display())?;
+        }
+    }
+    Ok(())
+}
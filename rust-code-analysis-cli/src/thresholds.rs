@@ -0,0 +1,286 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_code_analysis::{CodeMetrics, FuncSpace, SpaceKind, LANG};
+
+use crate::config::get_language;
+
+/// The metrics which can be checked against a threshold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+    Cyclomatic,
+    Cognitive,
+    Nargs,
+    Nexits,
+    Sloc,
+    Mi,
+}
+
+impl Metric {
+    pub fn all() -> &'static [Metric] {
+        &[
+            Metric::Cyclomatic,
+            Metric::Cognitive,
+            Metric::Nargs,
+            Metric::Nexits,
+            Metric::Sloc,
+            Metric::Mi,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::Cyclomatic => "cyclomatic",
+            Metric::Cognitive => "cognitive",
+            Metric::Nargs => "nargs",
+            Metric::Nexits => "nexits",
+            Metric::Sloc => "sloc",
+            Metric::Mi => "mi",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Metric::Cyclomatic => "Cyclomatic complexity",
+            Metric::Cognitive => "Cognitive complexity",
+            Metric::Nargs => "Number of arguments",
+            Metric::Nexits => "Number of exit points",
+            Metric::Sloc => "Number of source lines",
+            Metric::Mi => "Maintainability index (Visual Studio variant)",
+        }
+    }
+
+    pub fn value(&self, metrics: &CodeMetrics) -> f64 {
+        match self {
+            Metric::Cyclomatic => metrics.cyclomatic.cyclomatic(),
+            Metric::Cognitive => metrics.cognitive.cognitive(),
+            Metric::Nargs => metrics.nargs.nargs(),
+            Metric::Nexits => metrics.nexits.exit(),
+            Metric::Sloc => metrics.loc.sloc(),
+            Metric::Mi => metrics.mi.mi_visual_studio(),
+        }
+    }
+
+    /// Returns true if a value violates a threshold
+    ///
+    /// The lower the maintainability index, the worse the code,
+    /// while the other metrics are worse when they are higher.
+    pub fn exceeds(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Metric::Mi => value < threshold,
+            _ => value > threshold,
+        }
+    }
+
+    fn default_threshold(&self) -> f64 {
+        match self {
+            Metric::Cyclomatic => 10.,
+            Metric::Cognitive => 15.,
+            Metric::Nargs => 7.,
+            Metric::Nexits => 5.,
+            Metric::Sloc => 200.,
+            Metric::Mi => 20.,
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(metric: &str) -> Result<Self, Self::Err> {
+        Metric::all()
+            .iter()
+            .find(|m| m.name() == metric)
+            .copied()
+            .ok_or_else(|| format!("{:?} is not a metric with a threshold", metric))
+    }
+}
+
+/// A threshold exceeded by a space.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub metric: Metric,
+    pub value: f64,
+    pub threshold: f64,
+}
+
+/// The threshold of a metric, which can be restricted to a language.
+#[derive(Debug, Clone)]
+struct Threshold {
+    language: Option<&'static str>,
+    kind: SpaceKind,
+    metric: Metric,
+    value: f64,
+}
+
+/// The thresholds of the metrics of the spaces.
+///
+/// A threshold applies to a kind of space, functions when it is not given,
+/// and it can be restricted to a language. A threshold restricted to
+/// a language takes precedence over a general one.
+#[derive(Debug, Clone)]
+pub struct Thresholds {
+    thresholds: Vec<Threshold>,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            thresholds: Metric::all()
+                .iter()
+                .map(|metric| Threshold {
+                    language: None,
+                    kind: SpaceKind::Function,
+                    metric: *metric,
+                    value: metric.default_threshold(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn parse_language(language: &str) -> Option<&'static str> {
+    get_language(language).map(|lang| lang.get_name())
+}
+
+impl Thresholds {
+    /// Replaces the threshold of a metric
+    pub fn set(
+        &mut self,
+        language: Option<&'static str>,
+        kind: SpaceKind,
+        metric: Metric,
+        value: f64,
+    ) {
+        self.thresholds
+            .retain(|t| !(t.language == language && t.kind == kind && t.metric == metric));
+        self.thresholds.push(Threshold {
+            language,
+            kind,
+            metric,
+            value,
+        });
+    }
+
+    /// Parses a threshold written as `[language:][kind:]metric=value`,
+    /// e.g. `cyclomatic=10` or `python:class:sloc=500`
+    pub fn parse(&mut self, threshold: &str) -> Result<(), String> {
+        let err = || format!("{:?} is not a valid threshold", threshold);
+        let mut parts = threshold.splitn(2, '=');
+        let mut scope: Vec<&str> = parts.next().unwrap_or("").split(':').collect();
+        let value = parts
+            .next()
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .ok_or_else(err)?;
+        let metric = scope.pop().unwrap_or("").trim().parse::<Metric>()?;
+
+        let mut language = None;
+        let mut kind = SpaceKind::Function;
+        match scope.as_slice() {
+            [] => {}
+            [name] => {
+                if let Ok(k) = name.parse::<SpaceKind>() {
+                    kind = k;
+                } else {
+                    language = Some(parse_language(name).ok_or_else(err)?);
+                }
+            }
+            [lang, k] => {
+                language = Some(parse_language(lang).ok_or_else(err)?);
+                kind = k.parse::<SpaceKind>()?;
+            }
+            _ => return Err(err()),
+        }
+        self.set(language, kind, metric, value);
+        Ok(())
+    }
+
+    /// Reads the thresholds from a `toml` table
+    ///
+    /// The metrics are the keys of the table, while a kind of space
+    /// or a language can be a nested table:
+    ///
+    /// ```toml
+    /// cyclomatic = 10
+    ///
+    /// [class]
+    /// sloc = 500
+    ///
+    /// [python]
+    /// cognitive = 10
+    ///
+    /// [python.class]
+    /// sloc = 300
+    /// ```
+    pub fn parse_toml(&mut self, table: &toml::value::Table) -> Result<(), String> {
+        self.parse_toml_table(table, None, None)
+    }
+
+    fn parse_toml_table(
+        &mut self,
+        table: &toml::value::Table,
+        language: Option<&'static str>,
+        kind: Option<SpaceKind>,
+    ) -> Result<(), String> {
+        for (key, value) in table {
+            if let Some(nested) = value.as_table() {
+                if kind.is_some() {
+                    return Err(format!("{:?} cannot contain other tables", key));
+                }
+                if let Ok(k) = key.parse::<SpaceKind>() {
+                    self.parse_toml_table(nested, language, Some(k))?;
+                } else if language.is_none() {
+                    let lang = parse_language(key)
+                        .ok_or_else(|| format!("{:?} is not a language or a space kind", key))?;
+                    self.parse_toml_table(nested, Some(lang), None)?;
+                } else {
+                    return Err(format!("{:?} is not a space kind", key));
+                }
+            } else {
+                let metric = key.parse::<Metric>()?;
+                let value = value
+                    .as_float()
+                    .or_else(|| value.as_integer().map(|v| v as f64))
+                    .ok_or_else(|| format!("The threshold of {:?} is not a number", key))?;
+                self.set(language, kind.unwrap_or(SpaceKind::Function), metric, value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the thresholds exceeded by the metrics of a space
+    pub fn violations(&self, space: &FuncSpace, language: &LANG) -> Vec<Violation> {
+        let language = language.get_name();
+        Metric::all()
+            .iter()
+            .filter_map(|metric| {
+                let threshold = self
+                    .thresholds
+                    .iter()
+                    .filter(|t| {
+                        t.kind == space.kind
+                            && t.metric == *metric
+                            && (t.language.is_none() || t.language == Some(language))
+                    })
+                    .max_by_key(|t| t.language.is_some())?
+                    .value;
+                let value = metric.value(&space.metrics);
+                if metric.exceeds(value, threshold) {
+                    Some(Violation {
+                        metric: *metric,
+                        value,
+                        threshold,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,39 @@
+use ignore::WalkBuilder;
+use std::path::Path;
+
+/// The name of the files containing the paths ignored by the tool,
+/// written with the `.gitignore` syntax.
+pub const IGNORE_FILE: &str = ".rust-code-analysis-ignore";
+
+/// The options used to walk the directories.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WalkOptions {
+    /// Do not respect the `.gitignore`, `.ignore`
+    /// and `.rust-code-analysis-ignore` files
+    pub no_ignore: bool,
+    /// Walk the hidden files and directories too
+    pub hidden: bool,
+    /// The number of threads used by a parallel walk, 0 to choose it
+    /// from the number of CPUs
+    pub threads: usize,
+}
+
+impl WalkOptions {
+    /// Returns a builder walking a path with these options.
+    ///
+    /// The ignore files are respected as `ripgrep` does: the `.gitignore`
+    /// files only inside a git repository, the `.ignore` and
+    /// `.rust-code-analysis-ignore` files everywhere, the latter taking
+    /// precedence over the former.
+    pub fn builder(&self, path: &Path) -> WalkBuilder {
+        let mut builder = WalkBuilder::new(path);
+        builder
+            .standard_filters(!self.no_ignore)
+            .hidden(!self.hidden)
+            .threads(self.threads);
+        if !self.no_ignore {
+            builder.add_custom_ignore_filename(IGNORE_FILE);
+        }
+        builder
+    }
+}
@@ -0,0 +1,115 @@
+use notify::{watcher, DebouncedEvent, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::channel;
+use std::time::Duration;
+
+use crate::walk::WalkOptions;
+
+/// The delay used to gather the events of a series of changes,
+/// e.g. when an editor saves a file.
+const DELAY: Duration = Duration::from_millis(500);
+
+/// The paths watched for changes.
+///
+/// Only the directories found walking the paths are tracked, so the changes
+/// to the ignored files and directories are not reported.
+pub struct Watch {
+    paths: Vec<PathBuf>,
+    walk: WalkOptions,
+    // The walked directories and the given files, indexed by their
+    // canonical path, since the paths of the events can be absolute
+    dirs: HashMap<PathBuf, PathBuf>,
+    files: HashMap<PathBuf, PathBuf>,
+}
+
+impl Watch {
+    /// Walks the paths to find the directories to track.
+    pub fn new(paths: &[String], walk: WalkOptions) -> Self {
+        let mut watch = Self {
+            paths: Vec::new(),
+            walk,
+            dirs: HashMap::new(),
+            files: HashMap::new(),
+        };
+        for path in paths {
+            let path = PathBuf::from(path);
+            if path.is_dir() {
+                for entry in walk.builder(&path).build().filter_map(|e| e.ok()) {
+                    if matches!(entry.file_type(), Some(file_type) if file_type.is_dir()) {
+                        if let Ok(full_path) = entry.path().canonicalize() {
+                            watch.dirs.insert(full_path, entry.into_path());
+                        }
+                    }
+                }
+            } else if let Ok(full_path) = path.canonicalize() {
+                watch.files.insert(full_path, path.clone());
+            } else {
+                continue;
+            }
+            watch.paths.push(path);
+        }
+        watch
+    }
+
+    // Returns the canonical path of a changed entry and its path as it
+    // would be found walking the paths, or None if the entry is ignored
+    fn walked_path(&self, path: &Path) -> Option<(PathBuf, PathBuf)> {
+        let full_path = path.canonicalize().ok()?;
+        if let Some(path) = self.files.get(&full_path) {
+            return Some((full_path, path.clone()));
+        }
+        let dir = self.dirs.get(full_path.parent()?)?;
+        let file_name = full_path.file_name()?;
+        // Walk the directory containing the entry, so that it is checked
+        // against the ignore files
+        let path = self
+            .walk
+            .builder(dir)
+            .max_depth(Some(1))
+            .build()
+            .filter_map(|e| e.ok())
+            .find(|e| e.depth() == 1 && e.file_name() == file_name)?
+            .into_path();
+        Some((full_path, path))
+    }
+
+    /// Waits for the changes in the paths and calls a function with the
+    /// files which have been created or modified, until an error occurs.
+    pub fn run<F: FnMut(Vec<PathBuf>)>(&mut self, mut on_change: F) -> notify::Result<()> {
+        let (sender, receiver) = channel();
+        let mut watcher = watcher(sender, DELAY)?;
+        for path in &self.paths {
+            watcher.watch(path, RecursiveMode::Recursive)?;
+        }
+
+        loop {
+            let event = receiver
+                .recv()
+                .map_err(|e| notify::Error::Generic(e.to_string()))?;
+            let mut changed = Vec::new();
+            for event in std::iter::once(event).chain(receiver.try_iter()) {
+                let path = match event {
+                    DebouncedEvent::Create(path)
+                    | DebouncedEvent::Write(path)
+                    | DebouncedEvent::Rename(_, path) => path,
+                    DebouncedEvent::Error(e, path) => {
+                        eprintln!("Warning: {} for path {:?}", e, path);
+                        continue;
+                    }
+                    _ => continue,
+                };
+                if let Some((full_path, path)) = self.walked_path(&path) {
+                    if path.is_dir() {
+                        self.dirs.insert(full_path, path);
+                    } else if path.is_file() && !changed.contains(&path) {
+                        changed.push(path);
+                    }
+                }
+            }
+            if !changed.is_empty() {
+                on_change(changed);
+            }
+        }
+    }
+}
@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::{self, Value};
 use std::path::PathBuf;
 
-use rust_code_analysis::{metrics, Callback, FuncSpace, ParserTrait};
+use rust_code_analysis::{
+    metrics_with_options, Callback, Error, FuncSpace, Metric, MetricsOptions, ParserTrait,
+};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct WebMetricsPayload {
@@ -10,6 +12,9 @@ pub struct WebMetricsPayload {
     pub file_name: String,
     pub code: String,
     pub unit: bool,
+    /// The metrics to compute, or all of them if missing
+    #[serde(default)]
+    pub metrics: Option<Vec<Metric>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -17,12 +22,17 @@ pub struct WebMetricsResponse {
     pub id: String,
     pub language: String,
     pub spaces: Option<FuncSpace>,
+    /// The reason why the metrics have not been computed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct WebMetricsInfo {
     pub file_name: String,
     pub unit: Option<String>,
+    /// The comma-separated metrics to compute, or all of them if missing
+    pub metrics: Option<String>,
 }
 
 pub struct WebMetricsCallback {}
@@ -32,6 +42,7 @@ pub struct WebMetricsCfg {
     pub path: PathBuf,
     pub unit: bool,
     pub language: String,
+    pub metrics: Option<Vec<Metric>>,
 }
 
 impl Callback for WebMetricsCallback {
@@ -39,22 +50,27 @@ impl Callback for WebMetricsCallback {
     type Cfg = WebMetricsCfg;
 
     fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
-        let spaces = metrics(parser, &cfg.path);
-        let spaces = if cfg.unit {
-            if let Some(mut spaces) = spaces {
-                spaces.spaces.clear();
-                Some(spaces)
-            } else {
-                None
+        let options = MetricsOptions {
+            metrics: cfg.metrics,
+            ..MetricsOptions::default()
+        };
+        // The metrics of a code containing some syntax errors are computed
+        // anyway, reporting the number of errors in each space
+        let (spaces, error) = match metrics_with_options(parser, &cfg.path, &options) {
+            Some(mut spaces) => {
+                if cfg.unit {
+                    spaces.spaces.clear();
+                }
+                (Some(spaces), None)
             }
-        } else {
-            spaces
+            None => (None, Some(Error::MetricsNotSupported.to_string())),
         };
 
         serde_json::to_value(WebMetricsResponse {
             id: cfg.id,
             language: cfg.language,
             spaces,
+            error,
         })
         .unwrap()
     }
@@ -12,7 +12,7 @@ use super::comment::{WebCommentCallback, WebCommentCfg, WebCommentInfo, WebComme
 use super::function::{WebFunctionCallback, WebFunctionCfg, WebFunctionInfo, WebFunctionPayload};
 use super::metrics::{WebMetricsCallback, WebMetricsCfg, WebMetricsInfo, WebMetricsPayload};
 
-use rust_code_analysis::{action, guess_language, AstCallback, AstCfg, AstPayload, LANG};
+use rust_code_analysis::{action, guess_language, AstCallback, AstCfg, AstPayload, Metric, LANG};
 
 const INVALID_LANGUAGE: &str = "The file extension doesn't correspond to a valid language";
 
@@ -123,6 +123,7 @@ fn metrics_json(item: web::Json<WebMetricsPayload>) -> HttpResponse {
             path,
             unit: payload.unit,
             language: name,
+            metrics: payload.metrics,
         };
         HttpResponse::Ok().json(action::<WebMetricsCallback>(
             &language,
@@ -144,6 +145,24 @@ async fn metrics_plain(
     info: Query<WebMetricsInfo>,
 ) -> Result<HttpResponse, actix_web::Error> {
     let buf = get_code(body).await?;
+    let metrics = info
+        .metrics
+        .as_ref()
+        .map(|metrics| {
+            metrics
+                .split(',')
+                .map(|metric| metric.parse::<Metric>())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose();
+    let metrics = match metrics {
+        Ok(metrics) => metrics,
+        Err(e) => {
+            return Ok(HttpResponse::BadRequest()
+                .header(http::header::CONTENT_TYPE, "text/plain")
+                .body(format!("error: {}", e)))
+        }
+    };
     let path = PathBuf::from(&info.file_name);
     let (language, name) = guess_language(&buf, &path);
     if let Some(language) = language {
@@ -155,6 +174,7 @@ async fn metrics_plain(
                 .as_ref()
                 .map_or(false, |s| s == "1" || s == "true"),
             language: name,
+            metrics,
         };
         Ok(HttpResponse::Ok().json(action::<WebMetricsCallback>(
             &language,
@@ -621,6 +641,7 @@ mod tests {
                 file_name: "test.py".to_string(),
                 code: "# -*- Mode: Objective-C++; tab-width: 2; indent-tabs-mode: nil; c-basic-offset: 2 -*-\n\ndef foo():\n    pass\n".to_string(),
                 unit: false,
+                metrics: None,
             })
             .to_request();
 
@@ -629,11 +650,14 @@ mod tests {
             "id": "1234",
             "language": "python",
             "spaces": {"kind": "unit",
+                       "recursive": false,
                        "start_line": 1,
                        "end_line": 4,
                        "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0},
                                    "cognitive": {"sum": 0.0, "average": 0.0},
-                                   "nargs": {"sum": 0.0, "average": 0.0},
+                                   "nargs": {"sum": 0.0, "average": 0.0,
+                                             "functions": 0.0, "closures": 0.0,
+                                             "functions_average": 0.0, "closures_average": null},
                                    "nexits": {"sum": 0.0, "average": 0.0},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                 "difficulty": 1.0,
@@ -653,14 +677,24 @@ mod tests {
                                    "nom": {"functions": 1.0, "closures": 0.0, "total": 1.0},
                                    "mi": {"mi_original": 139.974_331_558_152_1,
                                           "mi_sei": 161.414_455_240_662_22,
-                                          "mi_visual_studio": 81.856_334_244_533_39}},
+                                          "mi_visual_studio": 81.856_334_244_533_39},
+                                   "ck": {"classes": 0.0,
+                                          "wmc": 0.0,
+                                          "dit": 0.0,
+                                          "noc": 0.0,
+                                          "cbo": 0.0,
+                                          "rfc": 0.0,
+                                          "lcom": 0.0}},
                        "name": "test.py",
                        "spaces": [{"kind": "function",
+                                   "recursive": false,
                                    "start_line": 3,
                                    "end_line": 4,
                                    "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0},
                                                "cognitive": {"sum": 0.0, "average": 0.0},
-                                               "nargs": {"sum": 0.0, "average": 0.0},
+                                               "nargs": {"sum": 0.0, "average": 0.0,
+                                                         "functions": 0.0, "closures": 0.0,
+                                                         "functions_average": 0.0, "closures_average": null},
                                                "nexits": {"sum": 0.0, "average": 0.0},
                                                "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                             "difficulty": 1.0,
@@ -680,7 +714,14 @@ mod tests {
                                                "nom": {"functions": 1.0, "closures": 0.0, "total": 1.0},
                                                "mi": {"mi_original": 151.433_315_883_223_23,
                                                       "mi_sei": 142.873_061_717_489_78,
-                                                      "mi_visual_studio": 88.557_494_668_551_6}},
+                                                      "mi_visual_studio": 88.557_494_668_551_6},
+                                               "ck": {"classes": 0.0,
+                                                      "wmc": 0.0,
+                                                      "dit": 0.0,
+                                                      "noc": 0.0,
+                                                      "cbo": 0.0,
+                                                      "rfc": 0.0,
+                                                      "lcom": 0.0}},
                                    "name": "foo",
                                    "spaces": []}]}
         });
@@ -701,6 +742,7 @@ mod tests {
                 file_name: "test.py".to_string(),
                 code: "def foo():\n    pass\n".to_string(),
                 unit: true,
+                metrics: None,
             })
             .to_request();
 
@@ -709,11 +751,14 @@ mod tests {
             "id": "1234",
             "language": "python",
             "spaces": {"kind": "unit",
+                       "recursive": false,
                        "start_line": 1,
                        "end_line": 2,
                        "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0},
                                    "cognitive": {"sum": 0.0, "average": 0.0},
-                                   "nargs": {"sum": 0.0, "average": 0.0},
+                                   "nargs": {"sum": 0.0, "average": 0.0,
+                                             "functions": 0.0, "closures": 0.0,
+                                             "functions_average": 0.0, "closures_average": null},
                                    "nexits": {"sum": 0.0, "average": 0.0},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                 "difficulty": 1.0,
@@ -733,7 +778,14 @@ mod tests {
                                    "nom": {"functions": 1.0, "closures": 0.0, "total": 1.0},
                                    "mi": {"mi_original": 151.203_315_883_223_2,
                                           "mi_sei": 142.643_061_717_489_76,
-                                          "mi_visual_studio": 88.422_991_744_574_97}},
+                                          "mi_visual_studio": 88.422_991_744_574_97},
+                                   "ck": {"classes": 0.0,
+                                          "wmc": 0.0,
+                                          "dit": 0.0,
+                                          "noc": 0.0,
+                                          "cbo": 0.0,
+                                          "rfc": 0.0,
+                                          "lcom": 0.0}},
                        "name": "test.py",
                        "spaces": []}
         });
@@ -741,6 +793,66 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[actix_rt::test]
+    async fn test_web_metrics_json_selection() {
+        let mut app = test::init_service(
+            App::new().service(web::resource("/metrics").route(web::post().to(metrics_json))),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/metrics")
+            .set_json(&WebMetricsPayload {
+                id: "1234".to_string(),
+                file_name: "test.py".to_string(),
+                code: "def foo():\n    pass\n".to_string(),
+                unit: true,
+                metrics: Some(vec![Metric::Loc, Metric::Cyclomatic]),
+            })
+            .to_request();
+
+        let res: Value = test::read_response_json(&mut app, req).await;
+        let expected = json!({
+            "id": "1234",
+            "language": "python",
+            "spaces": {"kind": "unit",
+                       "recursive": false,
+                       "start_line": 1,
+                       "end_line": 2,
+                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0},
+                                   "loc": {"cloc": 0.0, "ploc": 2.0, "lloc": 1.0, "sloc": 2.0, "blank": 0.0}},
+                       "name": "test.py",
+                       "spaces": []}
+        });
+
+        assert_eq!(res, expected);
+    }
+
+    #[actix_rt::test]
+    async fn test_web_metrics_json_parse_errors() {
+        let mut app = test::init_service(
+            App::new().service(web::resource("/metrics").route(web::post().to(metrics_json))),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/metrics")
+            .set_json(&WebMetricsPayload {
+                id: "1234".to_string(),
+                file_name: "test.py".to_string(),
+                code: "def foo(:\n    pass\n".to_string(),
+                unit: false,
+                metrics: None,
+            })
+            .to_request();
+
+        let res: Value = test::read_response_json(&mut app, req).await;
+
+        // The metrics are computed anyway, along with the syntax errors
+        assert!(res.get("error").is_none());
+        assert_eq!(res["spaces"]["parse_errors"], 1);
+        assert_eq!(res["spaces"]["spaces"][0]["name"], "foo");
+        assert_eq!(res["spaces"]["spaces"][0]["errors"][0]["kind"], ")");
+    }
+
     #[actix_rt::test]
     async fn test_web_metrics_plain() {
         let mut app = test::init_service(
@@ -758,11 +870,14 @@ mod tests {
             "id": "",
             "language": "python",
             "spaces": {"kind": "unit",
+                       "recursive": false,
                        "start_line": 1,
                        "end_line": 2,
                        "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0},
                                    "cognitive": {"sum": 0.0, "average": 0.0},
-                                   "nargs": {"sum": 0.0, "average": 0.0},
+                                   "nargs": {"sum": 0.0, "average": 0.0,
+                                             "functions": 0.0, "closures": 0.0,
+                                             "functions_average": 0.0, "closures_average": null},
                                    "nexits": {"sum": 0.0, "average": 0.0},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                 "difficulty": 1.0,
@@ -782,14 +897,24 @@ mod tests {
                                    "nom": {"functions": 1.0, "closures": 0.0, "total": 1.0},
                                    "mi": {"mi_original": 151.203_315_883_223_2,
                                           "mi_sei": 142.643_061_717_489_76,
-                                          "mi_visual_studio": 88.422_991_744_574_97}},
+                                          "mi_visual_studio": 88.422_991_744_574_97},
+                                   "ck": {"classes": 0.0,
+                                          "wmc": 0.0,
+                                          "dit": 0.0,
+                                          "noc": 0.0,
+                                          "cbo": 0.0,
+                                          "rfc": 0.0,
+                                          "lcom": 0.0}},
                        "name": "test.py",
                        "spaces": [{"kind": "function",
+                                   "recursive": false,
                                    "start_line": 1,
                                    "end_line": 2,
                                    "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0},
                                                "cognitive": {"sum": 0.0, "average": 0.0},
-                                               "nargs": {"sum": 0.0, "average": 0.0},
+                                               "nargs": {"sum": 0.0, "average": 0.0,
+                                                         "functions": 0.0, "closures": 0.0,
+                                                         "functions_average": 0.0, "closures_average": null},
                                                "nexits": {"sum": 0.0, "average": 0.0},
                                                "halstead": {"bugs": 0.000_942_552_557_372_941_4,
                                                             "difficulty": 1.0,
@@ -809,7 +934,14 @@ mod tests {
                                                "nom": {"functions": 1.0, "closures": 0.0, "total": 1.0},
                                                "mi": {"mi_original": 151.433_315_883_223_23,
                                                       "mi_sei": 142.873_061_717_489_78,
-                                                      "mi_visual_studio": 88.557_494_668_551_6}},
+                                                      "mi_visual_studio": 88.557_494_668_551_6},
+                                               "ck": {"classes": 0.0,
+                                                      "wmc": 0.0,
+                                                      "dit": 0.0,
+                                                      "noc": 0.0,
+                                                      "cbo": 0.0,
+                                                      "rfc": 0.0,
+                                                      "lcom": 0.0}},
                                    "name": "foo",
                                    "spaces": []}]}
         });
@@ -817,6 +949,34 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[actix_rt::test]
+    async fn test_web_metrics_plain_selection() {
+        let mut app = test::init_service(
+            App::new().service(web::resource("/metrics").route(web::post().to(metrics_plain))),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/metrics?file_name=test.py&unit=1&metrics=nom")
+            .set(ContentType::plaintext())
+            .set_payload("def foo():\n    pass\n")
+            .to_request();
+
+        let res: Value = test::read_response_json(&mut app, req).await;
+        assert_eq!(
+            res["spaces"]["metrics"],
+            json!({"nom": {"functions": 1.0, "closures": 0.0, "total": 1.0}})
+        );
+
+        let req = test::TestRequest::post()
+            .uri("/metrics?file_name=test.py&metrics=nom,foo")
+            .set(ContentType::plaintext())
+            .set_payload("def foo():\n    pass\n")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[actix_rt::test]
     async fn test_web_function_json() {
         let mut app = test::init_service(
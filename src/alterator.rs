@@ -75,6 +75,10 @@ impl Alterator for PythonCode {}
 
 impl Alterator for JavaCode {}
 
+impl Alterator for GoCode {}
+
+impl Alterator for CsharpCode {}
+
 impl Alterator for MozjsCode {
     fn alterate(node: &Node, code: &[u8], span: bool, children: Vec<AstNode>) -> AstNode {
         match Mozjs::from(node.object().kind_id()) {
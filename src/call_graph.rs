@@ -0,0 +1,478 @@
+use petgraph::{dot::Dot, graph::NodeIndex, Direction, Graph};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::spaces::{Call, FuncSpace, SpaceKind};
+
+/// The functions contained in a file, in pre-order, used to resolve
+/// the calls among them.
+///
+/// A call is resolved by name: an unqualified call, e.g. `f()`, can be made
+/// to any function of the file, while a call on the caller itself,
+/// e.g. `self.f()`, only to the methods of the same class or implementation.
+/// The calls on any other receiver, e.g. `a.f()`, are not resolved,
+/// since the type of the receiver is unknown.
+pub(crate) struct FileFunctions<'a> {
+    // Each function with the index of the type containing it, if any
+    functions: Vec<(&'a FuncSpace, Option<usize>)>,
+    by_name: HashMap<&'a str, Vec<usize>>,
+}
+
+impl<'a> FileFunctions<'a> {
+    pub(crate) fn new(space: &'a FuncSpace) -> Self {
+        let mut functions = Vec::new();
+        Self::collect(space, None, &mut 0, &mut functions);
+
+        let mut by_name: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, (function, _)) in functions.iter().enumerate() {
+            if let Some(name) = function.name.as_deref() {
+                by_name.entry(name).or_default().push(i);
+            }
+        }
+
+        Self { functions, by_name }
+    }
+
+    fn collect(
+        space: &'a FuncSpace,
+        mut owner: Option<usize>,
+        types: &mut usize,
+        functions: &mut Vec<(&'a FuncSpace, Option<usize>)>,
+    ) {
+        match space.kind {
+            SpaceKind::Function => functions.push((space, owner)),
+            SpaceKind::Class
+            | SpaceKind::Struct
+            | SpaceKind::Trait
+            | SpaceKind::Interface
+            | SpaceKind::Enum
+            | SpaceKind::Record
+            | SpaceKind::Impl => {
+                owner = Some(*types);
+                *types += 1;
+            }
+            _ => {}
+        }
+        for subspace in &space.spaces {
+            Self::collect(subspace, owner, types, functions);
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    pub(crate) fn get(&self, function: usize) -> &'a FuncSpace {
+        self.functions[function].0
+    }
+
+    /// Returns the functions which can be called by a call
+    /// made in a function.
+    pub(crate) fn callees<'b>(
+        &'b self,
+        caller: usize,
+        call: &'b Call,
+    ) -> impl Iterator<Item = usize> + 'b {
+        let owner = self.functions[caller].1;
+        let on_self = call.is_on_self();
+        self.by_name
+            .get(call.name.as_str())
+            .filter(|_| on_self || call.is_unqualified())
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(move |&callee| !on_self || self.functions[callee].1 == owner)
+    }
+}
+
+/// A function of a call graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct CallNode {
+    /// The path of the file containing the function
+    pub path: PathBuf,
+    /// The name of the function
+    pub name: String,
+    /// The first line of the function
+    pub start_line: usize,
+    /// The last line of the function
+    pub end_line: usize,
+}
+
+impl fmt::Display for CallNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}:{})",
+            self.name,
+            self.path.display(),
+            self.start_line
+        )
+    }
+}
+
+/// The call graph of a series of files.
+///
+/// Each node is a function and each edge links a caller to a callee,
+/// the weight of an edge being the number of calls between them.
+/// A callee is resolved just by name, so all the functions having the name
+/// of a callee are linked to its caller, while a call on the caller itself,
+/// e.g. `self.f()`, is linked only to the methods of the same class.
+/// Calls made outside of a function are not considered.
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    graph: Graph<CallNode, usize>,
+    files: HashMap<PathBuf, HashMap<String, Vec<NodeIndex>>>,
+    unresolved: Vec<(NodeIndex, String)>,
+}
+
+impl CallGraph {
+    /// Adds the functions of a file to the call graph, linking
+    /// the calls among the functions of this file.
+    ///
+    /// The calls to functions which are not contained in the file
+    /// are kept, so that they can be linked later by [`link_files`].
+    ///
+    /// [`link_files`]: #method.link_files
+    pub fn add_file(&mut self, path: &Path, space: &FuncSpace) {
+        let functions = FileFunctions::new(space);
+        let mut names: HashMap<String, Vec<NodeIndex>> = HashMap::new();
+        let nodes: Vec<NodeIndex> = (0..functions.len())
+            .map(|i| {
+                let function = functions.get(i);
+                let node = self.graph.add_node(CallNode {
+                    path: path.to_path_buf(),
+                    name: function.name.clone().unwrap_or_default(),
+                    start_line: function.start_line,
+                    end_line: function.end_line,
+                });
+                if let Some(name) = &function.name {
+                    names.entry(name.to_string()).or_default().push(node);
+                }
+                node
+            })
+            .collect();
+
+        for (caller, &node) in nodes.iter().enumerate() {
+            for call in &functions.get(caller).calls {
+                let callees: Vec<usize> = functions.callees(caller, call).collect();
+                // Only an unqualified call can be made to a function
+                // defined in another file
+                if callees.is_empty() && call.is_unqualified() {
+                    self.unresolved.push((node, call.name.clone()));
+                }
+                for callee in callees {
+                    self.add_call(node, nodes[callee]);
+                }
+            }
+        }
+
+        self.files.insert(path.to_path_buf(), names);
+    }
+
+    fn add_call(&mut self, caller: NodeIndex, callee: NodeIndex) {
+        if let Some(edge) = self.graph.find_edge(caller, callee) {
+            self.graph[edge] += 1;
+        } else {
+            self.graph.add_edge(caller, callee, 1);
+        }
+    }
+
+    /// Links the unqualified calls to functions defined in other files
+    /// of the call graph.
+    ///
+    /// A call is linked only when exactly one function having its name
+    /// is defined in the other files, otherwise it is left unresolved,
+    /// since common names, such as `new` or `get`, would link
+    /// many unrelated functions.
+    pub fn link_files(&mut self) {
+        let unresolved = std::mem::take(&mut self.unresolved);
+        for (caller, callee) in unresolved {
+            let callees: Vec<NodeIndex> = self
+                .files
+                .iter()
+                .filter(|(path, _)| **path != self.graph[caller].path)
+                .filter_map(|(_, names)| names.get(&callee))
+                .flatten()
+                .copied()
+                .collect();
+            if let [callee] = callees[..] {
+                self.add_call(caller, callee);
+            } else {
+                self.unresolved.push((caller, callee));
+            }
+        }
+    }
+
+    /// Returns the functions of the call graph,
+    /// sorted by their paths and their lines.
+    pub fn functions(&self) -> impl Iterator<Item = (NodeIndex, &CallNode)> {
+        self.sorted_functions()
+            .into_iter()
+            .map(move |index| (index, &self.graph[index]))
+    }
+
+    // The files are added in any order, e.g. by parallel threads,
+    // so the functions are sorted to get the same output at each run
+    fn sorted_functions(&self) -> Vec<NodeIndex> {
+        let mut functions: Vec<NodeIndex> = self.graph.node_indices().collect();
+        functions.sort_by(|a, b| {
+            let (a, b) = (&self.graph[*a], &self.graph[*b]);
+            (&a.path, a.start_line, &a.name).cmp(&(&b.path, b.start_line, &b.name))
+        });
+        functions
+    }
+
+    // A copy of the graph whose nodes and edges are sorted
+    fn sorted_graph(&self) -> Graph<&CallNode, usize> {
+        let functions = self.sorted_functions();
+        let mut ids = vec![0; functions.len()];
+        let mut graph = Graph::with_capacity(functions.len(), self.graph.edge_count());
+        for (id, function) in functions.into_iter().enumerate() {
+            ids[function.index()] = id;
+            graph.add_node(&self.graph[function]);
+        }
+
+        let mut calls: Vec<(usize, usize, usize)> = self
+            .graph
+            .edge_indices()
+            .filter_map(|edge| {
+                let (caller, callee) = self.graph.edge_endpoints(edge)?;
+                Some((ids[caller.index()], ids[callee.index()], self.graph[edge]))
+            })
+            .collect();
+        calls.sort_unstable();
+        for (caller, callee, weight) in calls {
+            graph.add_edge(NodeIndex::new(caller), NodeIndex::new(callee), weight);
+        }
+        graph
+    }
+
+    /// Returns the number of distinct functions calling a function.
+    pub fn fan_in(&self, function: NodeIndex) -> usize {
+        self.graph
+            .neighbors_directed(function, Direction::Incoming)
+            .count()
+    }
+
+    /// Returns the number of distinct functions called by a function.
+    pub fn fan_out(&self, function: NodeIndex) -> usize {
+        self.graph
+            .neighbors_directed(function, Direction::Outgoing)
+            .count()
+    }
+
+    /// Returns the call graph in the `Graphviz DOT` format.
+    pub fn dot(&self) -> String {
+        format!("{}", Dot::new(&self.sorted_graph()))
+    }
+}
+
+#[derive(Serialize)]
+struct SerializedFunction<'a> {
+    id: usize,
+    #[serde(flatten)]
+    function: &'a CallNode,
+    fan_in: usize,
+    fan_out: usize,
+}
+
+#[derive(Serialize)]
+struct SerializedCall {
+    caller: usize,
+    callee: usize,
+    calls: usize,
+}
+
+impl Serialize for CallGraph {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let graph = self.sorted_graph();
+        let functions: Vec<_> = graph
+            .node_indices()
+            .map(|index| SerializedFunction {
+                id: index.index(),
+                function: graph[index],
+                fan_in: graph.neighbors_directed(index, Direction::Incoming).count(),
+                fan_out: graph.neighbors_directed(index, Direction::Outgoing).count(),
+            })
+            .collect();
+        let calls: Vec<_> = graph
+            .edge_indices()
+            .filter_map(|edge| {
+                let (caller, callee) = graph.edge_endpoints(edge)?;
+                Some(SerializedCall {
+                    caller: caller.index(),
+                    callee: callee.index(),
+                    calls: graph[edge],
+                })
+            })
+            .collect();
+
+        let mut st = serializer.serialize_struct("call_graph", 2)?;
+        st.serialize_field("functions", &functions)?;
+        st.serialize_field("calls", &calls)?;
+        st.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::*;
+
+    fn add_file<T: ParserTrait>(call_graph: &mut CallGraph, source: &str, file: &str) {
+        let path = PathBuf::from(file);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        let space = metrics(&parser, &path).unwrap();
+        call_graph.add_file(&path, &space);
+    }
+
+    fn calls(call_graph: &CallGraph) -> Vec<(String, String, usize)> {
+        let graph = &call_graph.graph;
+        let mut calls: Vec<_> = graph
+            .edge_indices()
+            .map(|edge| {
+                let (caller, callee) = graph.edge_endpoints(edge).unwrap();
+                (
+                    graph[caller].name.clone(),
+                    graph[callee].name.clone(),
+                    graph[edge],
+                )
+            })
+            .collect();
+        calls.sort();
+        calls
+    }
+
+    #[test]
+    fn python_call_graph() {
+        let mut call_graph = CallGraph::default();
+        add_file::<PythonParser>(
+            &mut call_graph,
+            "def a():
+    b()
+    b()
+    c()
+def b():
+    c()
+def c():
+    pass
+",
+            "foo.py",
+        );
+
+        assert_eq!(
+            calls(&call_graph),
+            vec![
+                ("a".to_string(), "b".to_string(), 2),
+                ("a".to_string(), "c".to_string(), 1),
+                ("b".to_string(), "c".to_string(), 1),
+            ]
+        );
+
+        let fans: Vec<_> = call_graph
+            .functions()
+            .map(|(index, function)| {
+                (
+                    function.name.as_str(),
+                    call_graph.fan_in(index),
+                    call_graph.fan_out(index),
+                )
+            })
+            .collect();
+        assert_eq!(fans, vec![("a", 0, 2), ("b", 1, 1), ("c", 2, 0)]);
+    }
+
+    #[test]
+    fn call_graph_without_cognitive() {
+        let path = PathBuf::from("foo.py");
+        let source = "def a():\n    b()\ndef b():\n    pass\n";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+        let options = MetricsOptions {
+            metrics: Some(vec![Metric::Loc]),
+            ..Default::default()
+        };
+        let space = metrics_with_options(&parser, &path, &options).unwrap();
+
+        // The calls do not depend on the selected metrics,
+        // and they are kept by the serialized spaces
+        let deserialized: FuncSpace =
+            serde_json::from_value(serde_json::to_value(&space).unwrap()).unwrap();
+        for space in &[space, deserialized] {
+            let mut call_graph = CallGraph::default();
+            call_graph.add_file(&path, space);
+            assert_eq!(
+                calls(&call_graph),
+                vec![("a".to_string(), "b".to_string(), 1)]
+            );
+        }
+    }
+
+    #[test]
+    fn call_graph_across_files() {
+        let mut call_graph = CallGraph::default();
+        add_file::<RustParser>(&mut call_graph, "fn a() { b(); x.c(); d(); }", "a.rs");
+        add_file::<RustParser>(&mut call_graph, "fn b() { e(); }\nfn d() {}", "b.rs");
+        add_file::<RustParser>(&mut call_graph, "fn c() {}\nfn d() {}", "c.rs");
+
+        assert!(calls(&call_graph).is_empty());
+
+        // A qualified call, or a call to a function defined
+        // in several files, is not linked
+        call_graph.link_files();
+        assert_eq!(
+            calls(&call_graph),
+            vec![("a".to_string(), "b".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn call_graph_sorted() {
+        let mut call_graph = CallGraph::default();
+        add_file::<PythonParser>(&mut call_graph, "def b():\n    pass\n", "b.py");
+        add_file::<PythonParser>(
+            &mut call_graph,
+            "def c():\n    d()\ndef d():\n    pass\n",
+            "a.py",
+        );
+
+        // The output does not depend on the order of the files
+        let names: Vec<_> = call_graph
+            .functions()
+            .map(|(_, function)| function.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["c", "d", "b"]);
+        let value = serde_json::to_value(&call_graph).unwrap();
+        assert_eq!(value["functions"][2]["name"], "b");
+        assert_eq!(
+            value["calls"],
+            serde_json::json!([{"caller": 0, "callee": 1, "calls": 1}])
+        );
+        assert!(call_graph.dot().contains("0 -> 1 [ label = \"1\" ]"));
+    }
+
+    #[test]
+    fn call_graph_formats() {
+        let mut call_graph = CallGraph::default();
+        add_file::<JavaParser>(
+            &mut call_graph,
+            "class X { void a() { b(); } void b() {} }",
+            "X.java",
+        );
+
+        assert_eq!(
+            serde_json::to_string(&call_graph).unwrap(),
+            "{\"functions\":[\
+             {\"id\":0,\"path\":\"X.java\",\"name\":\"a\",\"start_line\":1,\"end_line\":1,\"fan_in\":0,\"fan_out\":1},\
+             {\"id\":1,\"path\":\"X.java\",\"name\":\"b\",\"start_line\":1,\"end_line\":1,\"fan_in\":1,\"fan_out\":0}],\
+             \"calls\":[{\"caller\":0,\"callee\":1,\"calls\":1}]}"
+        );
+        assert!(call_graph.dot().contains("0 -> 1 [ label = \"1\" ]"));
+    }
+}
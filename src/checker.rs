@@ -19,6 +19,7 @@ pub trait Checker {
     fn is_string(node: &Node) -> bool;
     fn is_call(node: &Node) -> bool;
     fn is_func(node: &Node) -> bool;
+    fn is_closure(node: &Node) -> bool;
     fn is_func_space(node: &Node) -> bool;
     fn is_non_arg(node: &Node) -> bool;
 
@@ -32,6 +33,7 @@ impl Checker for PreprocCode {
     mk_checker!(is_string, StringLiteral, RawStringLiteral);
     mk_checker!(is_call,);
     mk_checker!(is_func,);
+    mk_checker!(is_closure,);
     mk_checker!(is_func_space,);
     mk_checker!(is_non_arg,);
 }
@@ -41,6 +43,7 @@ impl Checker for CcommentCode {
     mk_checker!(is_string, StringLiteral, RawStringLiteral);
     mk_checker!(is_call,);
     mk_checker!(is_func,);
+    mk_checker!(is_closure,);
     mk_checker!(is_func_space,);
     mk_checker!(is_non_arg,);
 
@@ -68,6 +71,7 @@ impl Checker for CppCode {
         FunctionDefinition2,
         FunctionDefinition3
     );
+    mk_checker!(is_closure, LambdaExpression);
     mk_checker!(
         is_func_space,
         TranslationUnit,
@@ -106,6 +110,7 @@ impl Checker for PythonCode {
     mk_checker!(is_string, String, ConcatenatedString);
     mk_checker!(is_call, Call);
     mk_checker!(is_func, FunctionDefinition);
+    mk_checker!(is_closure, Lambda);
     mk_checker!(is_func_space, Module, FunctionDefinition, ClassDefinition);
     mk_checker!(is_non_arg, LPAREN, COMMA, RPAREN);
 }
@@ -114,9 +119,86 @@ impl Checker for JavaCode {
     mk_checker!(is_comment, Comment);
     mk_checker!(is_string, StringLiteral);
     mk_checker!(is_call, MethodInvocation);
-    mk_checker!(is_func, MethodDeclaration);
-    mk_checker!(is_func_space, Program, ClassDeclaration);
-    mk_checker!(is_non_arg,);
+    mk_checker!(
+        is_func,
+        MethodDeclaration,
+        ConstructorDeclaration,
+        LambdaExpression
+    );
+    mk_checker!(is_closure, LambdaExpression);
+    mk_checker!(
+        is_func_space,
+        Program,
+        ClassDeclaration,
+        InterfaceDeclaration,
+        EnumDeclaration,
+        MethodDeclaration,
+        ConstructorDeclaration,
+        LambdaExpression
+    );
+    mk_else_if!(IfStatement);
+    mk_checker!(is_non_arg, LPAREN, COMMA, RPAREN, ReceiverParameter);
+}
+
+impl Checker for GoCode {
+    mk_checker!(is_comment, Comment);
+    mk_checker!(is_string, InterpretedStringLiteral, RawStringLiteral);
+    mk_checker!(is_call, CallExpression);
+    mk_checker!(is_func, FunctionDeclaration, MethodDeclaration, FuncLiteral);
+    mk_checker!(is_closure, FuncLiteral);
+    mk_checker!(
+        is_func_space,
+        SourceFile,
+        FunctionDeclaration,
+        MethodDeclaration,
+        FuncLiteral
+    );
+    mk_else_if!(IfStatement);
+    mk_checker!(is_non_arg, LPAREN, COMMA, RPAREN);
+}
+
+impl Checker for CsharpCode {
+    mk_checker!(is_comment, Comment);
+    mk_checker!(
+        is_string,
+        StringLiteral,
+        VerbatimStringLiteral,
+        InterpolatedStringExpression
+    );
+    mk_checker!(is_call, InvocationExpression);
+    mk_checker!(
+        is_func,
+        MethodDeclaration,
+        ConstructorDeclaration,
+        DestructorDeclaration,
+        OperatorDeclaration,
+        ConversionOperatorDeclaration,
+        LocalFunctionStatement,
+        AccessorDeclaration,
+        LambdaExpression,
+        AnonymousMethodExpression
+    );
+    mk_checker!(is_closure, LambdaExpression, AnonymousMethodExpression);
+    mk_checker!(
+        is_func_space,
+        CompilationUnit,
+        NamespaceDeclaration,
+        ClassDeclaration,
+        StructDeclaration,
+        InterfaceDeclaration,
+        RecordDeclaration,
+        MethodDeclaration,
+        ConstructorDeclaration,
+        DestructorDeclaration,
+        OperatorDeclaration,
+        ConversionOperatorDeclaration,
+        LocalFunctionStatement,
+        AccessorDeclaration,
+        LambdaExpression,
+        AnonymousMethodExpression
+    );
+    mk_else_if!(IfStatement);
+    mk_checker!(is_non_arg, LPAREN, COMMA, RPAREN);
 }
 
 impl Checker for MozjsCode {
@@ -132,6 +214,7 @@ impl Checker for MozjsCode {
         MethodDefinition,
         ArrowFunction
     );
+    mk_checker!(is_closure, GeneratorFunction, ArrowFunction);
     mk_checker!(
         is_func_space,
         Program,
@@ -171,6 +254,7 @@ impl Checker for JavascriptCode {
         MethodDefinition,
         ArrowFunction
     );
+    mk_checker!(is_closure, GeneratorFunction, ArrowFunction);
     mk_checker!(
         is_func_space,
         Program,
@@ -200,6 +284,7 @@ impl Checker for TypescriptCode {
         MethodDefinition,
         ArrowFunction
     );
+    mk_checker!(is_closure, GeneratorFunction, ArrowFunction);
     mk_checker!(
         is_func_space,
         Program,
@@ -229,6 +314,7 @@ impl Checker for TsxCode {
         MethodDefinition,
         ArrowFunction
     );
+    mk_checker!(is_closure, GeneratorFunction, ArrowFunction);
     mk_checker!(
         is_func_space,
         Program,
@@ -264,6 +350,7 @@ impl Checker for RustCode {
     mk_checker!(is_string, StringLiteral, RawStringLiteral);
     mk_checker!(is_call, CallExpression);
     mk_checker!(is_func, FunctionItem, ClosureExpression);
+    mk_checker!(is_closure, ClosureExpression);
     mk_checker!(
         is_func_space,
         SourceFile,
@@ -272,5 +359,5 @@ impl Checker for RustCode {
         TraitItem,
         ClosureExpression
     );
-    mk_checker!(is_non_arg, LPAREN, COMMA, RPAREN, AttributeItem);
+    mk_checker!(is_non_arg, LPAREN, COMMA, RPAREN, PIPE, AttributeItem);
 }
@@ -0,0 +1,356 @@
+use serde::Serialize;
+
+use crate::spaces::{FuncSpace, SpaceKind};
+
+/// The kind of change of a space between two versions of a code.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Change {
+    /// The space is contained only in the new version
+    Added,
+    /// The space is contained only in the old version
+    Removed,
+    /// The space has a different name, but the same body
+    Renamed,
+    /// The metrics of the space, or of its subspaces, are different
+    Modified,
+    /// The metrics of the space and of its subspaces are the same
+    Unchanged,
+}
+
+/// The values of a metric in two versions of a space.
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricDiff {
+    /// The name of the metric, e.g. `cyclomatic.sum`
+    pub name: String,
+    /// The value of the metric in the old version
+    pub old: f64,
+    /// The value of the metric in the new version
+    pub new: f64,
+}
+
+impl MetricDiff {
+    /// Returns the difference between the new and the old value
+    pub fn delta(&self) -> f64 {
+        self.new - self.old
+    }
+}
+
+/// The differences between two versions of a space.
+///
+/// The subspaces of the two versions are matched by kind and name.
+/// The remaining ones are matched when they have the same kind,
+/// the same number of lines and the same `Halstead` operators and operands,
+/// so a space is considered renamed only if its body has not changed.
+#[derive(Clone, Debug, Serialize)]
+pub struct SpaceDiff {
+    /// The name of the space, the old one if it has been removed
+    pub name: Option<String>,
+    /// The old name of a renamed space
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_name: Option<String>,
+    /// The space kind
+    pub kind: SpaceKind,
+    /// The kind of change of the space
+    pub change: Change,
+    /// The first and the last line of the space in the old version
+    pub old_lines: Option<(usize, usize)>,
+    /// The first and the last line of the space in the new version
+    pub new_lines: Option<(usize, usize)>,
+    /// The metrics having a different value in the two versions
+    pub metrics: Vec<MetricDiff>,
+    /// The subspaces which have changed, or all of them
+    /// if the space has been added or removed
+    pub spaces: Vec<SpaceDiff>,
+}
+
+impl SpaceDiff {
+    /// Compares two versions of a space
+    pub fn new(old: &FuncSpace, new: &FuncSpace) -> Self {
+        let old_values = old.metrics.flat_values();
+        let metrics: Vec<_> = new
+            .metrics
+            .flat_values()
+            .into_iter()
+            .filter_map(|(name, new)| {
+                let old = old_values
+                    .iter()
+                    .find(|(old_name, _)| *old_name == name)
+                    .map_or(f64::NAN, |(_, old)| *old);
+                if same_value(old, new) {
+                    None
+                } else {
+                    Some(MetricDiff { name, old, new })
+                }
+            })
+            .collect();
+        let spaces = diff_subspaces(&old.spaces, &new.spaces);
+        let change = if metrics.is_empty() && spaces.is_empty() {
+            Change::Unchanged
+        } else {
+            Change::Modified
+        };
+
+        Self {
+            name: new.name.clone(),
+            old_name: None,
+            kind: new.kind,
+            change,
+            old_lines: Some((old.start_line, old.end_line)),
+            new_lines: Some((new.start_line, new.end_line)),
+            metrics,
+            spaces,
+        }
+    }
+
+    /// Compares two versions of a space matched by [`match_spaces`],
+    /// one of them being absent if the space has been added or removed
+    ///
+    /// [`match_spaces`]: fn.match_spaces.html
+    pub fn from_match(old: Option<&FuncSpace>, new: Option<&FuncSpace>) -> Option<Self> {
+        match (old, new) {
+            (Some(old), Some(new)) => {
+                let mut diff = Self::new(old, new);
+                // Only the spaces having the same body are matched by name
+                if old.name != new.name {
+                    diff.old_name = old.name.clone();
+                    diff.change = Change::Renamed;
+                }
+                Some(diff)
+            }
+            (None, Some(new)) => Some(Self::added(new)),
+            (Some(old), None) => Some(Self::removed(old)),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the differences for a space contained only in the new version,
+    /// its subspaces being added too
+    pub fn added(space: &FuncSpace) -> Self {
+        Self::single(space, Change::Added)
+    }
+
+    /// Returns the differences for a space contained only in the old version,
+    /// its subspaces being removed too
+    pub fn removed(space: &FuncSpace) -> Self {
+        Self::single(space, Change::Removed)
+    }
+
+    fn single(space: &FuncSpace, change: Change) -> Self {
+        let lines = Some((space.start_line, space.end_line));
+        let (old_lines, new_lines) = if change == Change::Added {
+            (None, lines)
+        } else {
+            (lines, None)
+        };
+        Self {
+            name: space.name.clone(),
+            old_name: None,
+            kind: space.kind,
+            change,
+            old_lines,
+            new_lines,
+            metrics: Vec::new(),
+            spaces: space
+                .spaces
+                .iter()
+                .map(|space| Self::single(space, change))
+                .collect(),
+        }
+    }
+
+    /// Returns true if the space has changed
+    pub fn is_changed(&self) -> bool {
+        self.change != Change::Unchanged
+    }
+}
+
+fn same_value(old: f64, new: f64) -> bool {
+    // The values read from a file can differ in the last digits
+    // from the computed ones
+    (old.is_nan() && new.is_nan())
+        || old == new
+        || (old - new).abs() <= 1e-9 * old.abs().max(new.abs())
+}
+
+/// Returns the values of the metrics flattening their serialization,
+/// e.g. `cyclomatic.sum`, so non-scalar values are not considered.
+fn same_body(old: &FuncSpace, new: &FuncSpace) -> bool {
+    let (old_halstead, new_halstead) = (&old.metrics.halstead, &new.metrics.halstead);
+    old.kind == new.kind
+        && old.end_line - old.start_line == new.end_line - new.start_line
+        && old_halstead.u_operators() == new_halstead.u_operators()
+        && old_halstead.operators() == new_halstead.operators()
+        && old_halstead.u_operands() == new_halstead.u_operands()
+        && old_halstead.operands() == new_halstead.operands()
+}
+
+/// Matches two versions of a list of sibling spaces, as it is done
+/// for the subspaces of a [`SpaceDiff`].
+///
+/// The pairs follow the order of the new spaces, the spaces
+/// contained only in the new version being paired with `None`.
+/// The spaces contained only in the old version come last.
+///
+/// [`SpaceDiff`]: struct.SpaceDiff.html
+pub fn match_spaces<'a>(
+    old: &'a [FuncSpace],
+    new: &'a [FuncSpace],
+) -> Vec<(Option<&'a FuncSpace>, Option<&'a FuncSpace>)> {
+    let mut old_matched = vec![false; old.len()];
+    let mut new_matches: Vec<Option<usize>> = vec![None; new.len()];
+
+    // Spaces having the same name are matched in order,
+    // so that overloaded functions are paired too
+    for (space, new_match) in new.iter().zip(new_matches.iter_mut()) {
+        if let Some(i) = (0..old.len())
+            .find(|i| !old_matched[*i] && old[*i].kind == space.kind && old[*i].name == space.name)
+        {
+            old_matched[i] = true;
+            *new_match = Some(i);
+        }
+    }
+    for (space, new_match) in new.iter().zip(new_matches.iter_mut()) {
+        if new_match.is_some() {
+            continue;
+        }
+        if let Some(i) = (0..old.len()).find(|i| !old_matched[*i] && same_body(&old[*i], space)) {
+            old_matched[i] = true;
+            *new_match = Some(i);
+        }
+    }
+
+    let mut matches: Vec<_> = new
+        .iter()
+        .zip(new_matches)
+        .map(|(space, new_match)| (new_match.map(|i| &old[i]), Some(space)))
+        .collect();
+    matches.extend(
+        old.iter()
+            .zip(old_matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(space, _)| (Some(space), None)),
+    );
+    matches
+}
+
+fn diff_subspaces(old: &[FuncSpace], new: &[FuncSpace]) -> Vec<SpaceDiff> {
+    match_spaces(old, new)
+        .into_iter()
+        .filter_map(|(old, new)| SpaceDiff::from_match(old, new))
+        .filter(|diff| diff.is_changed())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::*;
+
+    fn changes(diff: &SpaceDiff) -> Vec<(Option<&str>, Option<&str>, Change)> {
+        diff.spaces
+            .iter()
+            .map(|space| {
+                (
+                    space.name.as_deref(),
+                    space.old_name.as_deref(),
+                    space.change,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn python_diff() {
+        let old = python_space(
+            "def a(x):
+    if x:
+        return 1
+def b(x, y):
+    return x + y
+def c():
+    pass
+",
+            &MetricsOptions::default(),
+        );
+        let new = python_space(
+            "def a(x):
+    if x:
+        if x > 1:
+            return 2
+        return 1
+def sum(x, y):
+    return x + y
+def d():
+    return 0
+",
+            &MetricsOptions::default(),
+        );
+
+        let diff = SpaceDiff::new(&old, &new);
+        assert_eq!(diff.change, Change::Modified);
+        assert_eq!(
+            changes(&diff),
+            vec![
+                (Some("a"), None, Change::Modified),
+                (Some("sum"), Some("b"), Change::Renamed),
+                (Some("d"), None, Change::Added),
+                (Some("c"), None, Change::Removed),
+            ]
+        );
+
+        let a = &diff.spaces[0];
+        assert_eq!(a.old_lines, Some((1, 3)));
+        assert_eq!(a.new_lines, Some((1, 5)));
+        let cyclomatic = a
+            .metrics
+            .iter()
+            .find(|metric| metric.name == "cyclomatic.sum")
+            .unwrap();
+        assert_eq!((cyclomatic.old, cyclomatic.new), (2., 3.));
+        assert_eq!(cyclomatic.delta(), 1.);
+    }
+
+    #[test]
+    fn diff_unchanged() {
+        let source = "def a(x):
+    return x
+";
+        let diff = SpaceDiff::new(
+            &python_space(source, &MetricsOptions::default()),
+            &python_space(source, &MetricsOptions::default()),
+        );
+        assert!(!diff.is_changed());
+        assert!(diff.metrics.is_empty());
+        assert!(diff.spaces.is_empty());
+    }
+
+    #[test]
+    fn deserialized_spaces() {
+        let space = python_space(
+            "def a(x, y):
+    # Comment
+    if x and y:
+        return lambda z: z + 1
+    return None
+
+class B:
+    def c(self):
+        pass
+",
+            &MetricsOptions::default(),
+        );
+
+        let json = serde_json::to_string(&space).unwrap();
+        let deserialized: FuncSpace = serde_json::from_str(&json).unwrap();
+        // The values parsed from a json string can differ in the last digits
+        assert!(!SpaceDiff::new(&space, &deserialized).is_changed());
+        assert_eq!(deserialized.spaces[1].kind, SpaceKind::Class);
+
+        // The values are obtained back exactly without parsing them
+        let value = serde_json::to_value(&space).unwrap();
+        let deserialized: FuncSpace = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&deserialized).unwrap(), value);
+    }
+}
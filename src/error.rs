@@ -0,0 +1,68 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+/// The errors which can occur analyzing a code.
+#[derive(Debug)]
+pub enum Error {
+    /// An error occurred reading the code
+    Io(io::Error),
+    /// The code is empty, or too short to contain anything to analyze
+    EmptyCode,
+    /// The code is not a valid `UTF-8` text, e.g. it is a binary file
+    NotUtf8,
+    /// The language of the code cannot be detected,
+    /// or it is not supported
+    UnsupportedLanguage,
+    /// The parse of the code produced some syntax errors
+    Parse {
+        /// The number of nodes which are errors or which have been
+        /// inserted by the parser to recover from an error
+        errors: usize,
+    },
+    /// The metrics cannot be computed for the language of the code,
+    /// e.g. the one of the comments
+    MetricsNotSupported,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::EmptyCode => write!(f, "The code is empty"),
+            Error::NotUtf8 => write!(f, "The code is not a valid UTF-8 text"),
+            Error::UnsupportedLanguage => write!(f, "The language of the code is not supported"),
+            Error::Parse { errors: 1 } => write!(f, "The code contains 1 syntax error"),
+            Error::Parse { errors } => write!(f, "The code contains {} syntax errors", errors),
+            Error::MetricsNotSupported => {
+                write!(f, "The metrics are not supported for the language")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        let kind = match e {
+            Error::Io(e) => return e,
+            Error::EmptyCode | Error::NotUtf8 | Error::Parse { .. } => io::ErrorKind::InvalidData,
+            Error::UnsupportedLanguage | Error::MetricsNotSupported => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, e)
+    }
+}
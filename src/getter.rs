@@ -20,6 +20,27 @@ pub trait Getter {
         }
     }
 
+    fn get_call<'a>(node: &Node, code: &'a [u8]) -> Option<(&'a str, Option<&'a str>)> {
+        // we're in a call: `f()`, `a.f()`, `a::f()`, ...
+        let mut callee = node
+            .object()
+            .child_by_field_name("function")
+            .or_else(|| node.object().child_by_field_name("name"))?;
+        // the called name is the last component of a qualified callee
+        while let Some(name) = ["name", "field", "property", "attribute"]
+            .iter()
+            .find_map(|field| callee.child_by_field_name(field))
+        {
+            callee = name;
+        }
+        let name = std::str::from_utf8(&code[callee.start_byte()..callee.end_byte()]).ok()?;
+        // while the receiver is what precedes it: `a` for `a.f()` or `a::f()`
+        let receiver = std::str::from_utf8(&code[node.object().start_byte()..callee.start_byte()])
+            .ok()?
+            .trim_end_matches(|c: char| ".:->?".contains(c) || c.is_whitespace());
+        Some((name, Some(receiver).filter(|receiver| !receiver.is_empty())))
+    }
+
     fn get_space_kind(_node: &Node) -> SpaceKind {
         SpaceKind::Unknown
     }
@@ -468,6 +489,167 @@ impl Getter for CppCode {
     }
 }
 
+impl Getter for JavaCode {
+    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+        if let Some(name) = node.object().child_by_field_name("name") {
+            let code = &code[name.start_byte()..name.end_byte()];
+            std::str::from_utf8(code).ok()
+        } else {
+            // We can be in a variable declaration: Runnable r = () -> {}
+            if let Some(parent) = node.object().parent() {
+                if let Java::VariableDeclarator = parent.kind_id().into() {
+                    if let Some(name) = parent.child_by_field_name("name") {
+                        let code = &code[name.start_byte()..name.end_byte()];
+                        return std::str::from_utf8(code).ok();
+                    }
+                }
+            }
+            Some("<anonymous>")
+        }
+    }
+
+    fn get_space_kind(node: &Node) -> SpaceKind {
+        use Java::*;
+
+        let typ = node.object().kind_id();
+        match typ.into() {
+            MethodDeclaration | ConstructorDeclaration | LambdaExpression => SpaceKind::Function,
+            ClassDeclaration => SpaceKind::Class,
+            InterfaceDeclaration => SpaceKind::Interface,
+            EnumDeclaration => SpaceKind::Enum,
+            Program => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+
+    fn get_op_type(node: &Node) -> HalsteadType {
+        use Java::*;
+
+        let id = node.object().kind_id();
+        match id.into() {
+            Import | Package | Extends | Implements | DOT | LPAREN | COMMA | COLON | SEMI
+            | COLONCOLON | DASHGT | Return | Break | Continue | Throw | Throws2 | If | Else
+            | Switch | Case | Default | For | While | Do | Try | Catch | Finally | Assert
+            | Synchronized | New | Instanceof | EQ | AMPAMP | PIPEPIPE | PLUS | DASH | STAR
+            | SLASH | PERCENT | PLUSPLUS | DASHDASH | PIPE | AMP | CARET | LTLT | GTGT | GTGTGT
+            | TILDE | BANG | LT | LTEQ | EQEQ | BANGEQ | GTEQ | GT | PLUSEQ | DASHEQ | STAREQ
+            | SLASHEQ | PERCENTEQ | AMPEQ | PIPEEQ | CARETEQ | LTLTEQ | GTGTEQ | GTGTGTEQ
+            | QMARK | LBRACK | LBRACE | AT | Byte | Short | Int | Long | Char | Float | Double
+            | BooleanType | VoidType => HalsteadType::Operator,
+            Identifier | TypeIdentifier | DecimalIntegerLiteral | HexIntegerLiteral
+            | OctalIntegerLiteral | BinaryIntegerLiteral | DecimalFloatingPointLiteral
+            | HexFloatingPointLiteral | CharacterLiteral | StringLiteral | True | False
+            | NullLiteral | This | Super => HalsteadType::Operand,
+            _ => HalsteadType::Unknown,
+        }
+    }
+}
+
+impl Getter for GoCode {
+    fn get_space_kind(node: &Node) -> SpaceKind {
+        use crate::languages::Go::*;
+
+        let typ = node.object().kind_id();
+        match typ.into() {
+            FunctionDeclaration | MethodDeclaration | FuncLiteral => SpaceKind::Function,
+            SourceFile => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+
+    fn get_op_type(node: &Node) -> HalsteadType {
+        use crate::languages::Go::*;
+
+        let id = node.object().kind_id();
+        match id.into() {
+            Package | Import | DOT | LPAREN | SEMI | COMMA | COLON | Const | Var | Func | Type
+            | Struct | Interface | Map | Chan | DOTDOTDOT | LBRACK | LBRACE | EQ | COLONEQ
+            | LTDASH | PLUSPLUS | DASHDASH | STAREQ | SLASHEQ | PERCENTEQ | LTLTEQ | GTGTEQ
+            | AMPEQ | AMPCARETEQ | PLUSEQ | DASHEQ | PIPEEQ | CARETEQ | Fallthrough | Break
+            | Continue | Goto | Return | Go | Defer | If | Else | For | Range | Switch | Case
+            | Default | Select | PLUS | DASH | STAR | SLASH | PERCENT | BANG | CARET | AMP
+            | LTLT | GTGT | AMPCARET | PIPE | EQEQ | BANGEQ | LT | LTEQ | GT | GTEQ | AMPAMP
+            | PIPEPIPE => HalsteadType::Operator,
+            Identifier | BlankIdentifier | FieldIdentifier | PackageIdentifier
+            | TypeIdentifier | IntLiteral | FloatLiteral | ImaginaryLiteral | RuneLiteral
+            | RawStringLiteral | InterpretedStringLiteral | Nil | True | False => {
+                HalsteadType::Operand
+            }
+            _ => HalsteadType::Unknown,
+        }
+    }
+}
+
+impl Getter for CsharpCode {
+    fn get_func_space_name<'a>(node: &Node, code: &'a [u8]) -> Option<&'a str> {
+        // An operator declaration has no name: operator +(...)
+        if let Some(name) = node
+            .object()
+            .child_by_field_name("name")
+            .or_else(|| node.object().child_by_field_name("operator"))
+        {
+            let code = &code[name.start_byte()..name.end_byte()];
+            std::str::from_utf8(code).ok()
+        } else {
+            // We can be in a variable declaration: Func<int, int> f = x => x;
+            if let Some(parent) = node.object().parent() {
+                if let Csharp::VariableDeclarator = parent.kind_id().into() {
+                    if let Some(name) = parent.child_by_field_name("name") {
+                        let code = &code[name.start_byte()..name.end_byte()];
+                        return std::str::from_utf8(code).ok();
+                    }
+                }
+            }
+            Some("<anonymous>")
+        }
+    }
+
+    fn get_space_kind(node: &Node) -> SpaceKind {
+        use Csharp::*;
+
+        let typ = node.object().kind_id();
+        match typ.into() {
+            MethodDeclaration
+            | ConstructorDeclaration
+            | DestructorDeclaration
+            | OperatorDeclaration
+            | ConversionOperatorDeclaration
+            | LocalFunctionStatement
+            | AccessorDeclaration
+            | LambdaExpression
+            | AnonymousMethodExpression => SpaceKind::Function,
+            ClassDeclaration => SpaceKind::Class,
+            StructDeclaration => SpaceKind::Struct,
+            InterfaceDeclaration => SpaceKind::Interface,
+            RecordDeclaration => SpaceKind::Record,
+            NamespaceDeclaration => SpaceKind::Namespace,
+            CompilationUnit => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+
+    fn get_op_type(node: &Node) -> HalsteadType {
+        use Csharp::*;
+
+        let id = node.object().kind_id();
+        match id.into() {
+            Using | Namespace | DOT | LPAREN | COMMA | COLON | SEMI | COLONCOLON | EQGT
+            | DASHGT | Return | Break | Continue | Goto | Throw | Yield | If | Else | Switch
+            | Case | Default | For | Foreach | In | While | Do | Try | Catch | When | Finally
+            | Lock | Checked | Unchecked | Fixed | Await | New | Is | As | Typeof | Sizeof
+            | Stackalloc | With | Not | And | Or | EQ | AMPAMP | PIPEPIPE | QMARKQMARK | PLUS
+            | DASH | STAR | SLASH | PERCENT | PLUSPLUS | DASHDASH | PIPE | AMP | CARET | LTLT
+            | GTGT | TILDE | BANG | LT | LTEQ | EQEQ | BANGEQ | GTEQ | GT | PLUSEQ | DASHEQ
+            | STAREQ | SLASHEQ | PERCENTEQ | AMPEQ | PIPEEQ | CARETEQ | LTLTEQ | GTGTEQ
+            | QMARKQMARKEQ | QMARK | DOTDOT | LBRACK | LBRACE | Var
+            | PredefinedType => HalsteadType::Operator,
+            Identifier | IntegerLiteral | RealLiteral | CharacterLiteral | StringLiteral
+            | VerbatimStringLiteral | BooleanLiteral | NullLiteral | This
+            | Base => HalsteadType::Operand,
+            _ => HalsteadType::Unknown,
+        }
+    }
+}
+
 impl Getter for PreprocCode {}
 impl Getter for CcommentCode {}
-impl Getter for JavaCode {}
@@ -45,6 +45,16 @@ mk_langs!(
         [java],
         ["java"]
     ),
+    (
+        Go,
+        "The `Go` language",
+        "go",
+        GoCode,
+        GoParser,
+        tree_sitter_go,
+        [go],
+        ["go"]
+    ),
     (
         Rust,
         "The `Rust` language",
@@ -65,6 +75,16 @@ mk_langs!(
         [cpp, cxx, cc, hxx, hpp, c, h, hh, inc, mm, m],
         ["c++", "c", "objc", "objc++", "objective-c++", "objective-c"]
     ),
+    (
+        Csharp,
+        "The `C#` language",
+        "c#",
+        CsharpCode,
+        CsharpParser,
+        tree_sitter_c_sharp,
+        [cs],
+        ["csharp"]
+    ),
     (
         Python,
         "The `Python` language",
@@ -0,0 +1,1649 @@
+// Code generated; DO NOT EDIT.
+
+#[derive(Clone, Debug, PartialEq, FromPrimitive)]
+pub enum Csharp {
+    End = 0,
+    IdentifierToken = 1,
+    Extern = 2,
+    Alias = 3,
+    SEMI = 4,
+    Using = 5,
+    Static = 6,
+    EQ = 7,
+    COLONCOLON = 8,
+    LT = 9,
+    COMMA = 10,
+    GT = 11,
+    DOT = 12,
+    LBRACK = 13,
+    RBRACK = 14,
+    Field = 15,
+    Event = 16,
+    Method = 17,
+    Param = 18,
+    Property = 19,
+    Return = 20,
+    Type2 = 21,
+    COLON = 22,
+    LPAREN = 23,
+    RPAREN = 24,
+    Assembly = 25,
+    Module = 26,
+    Abstract = 27,
+    Async = 28,
+    Const = 29,
+    Fixed = 30,
+    Internal = 31,
+    New = 32,
+    Override = 33,
+    Partial = 34,
+    Private = 35,
+    Protected = 36,
+    Public = 37,
+    Readonly = 38,
+    Ref = 39,
+    Sealed = 40,
+    Unsafe = 41,
+    Virtual = 42,
+    Volatile = 43,
+    Out = 44,
+    In = 45,
+    This = 46,
+    Params = 47,
+    Base = 48,
+    LBRACE = 49,
+    RBRACE = 50,
+    EQGT = 51,
+    Implicit = 52,
+    Explicit = 53,
+    Operator = 54,
+    TILDE = 55,
+    Where = 56,
+    Class = 57,
+    QMARK = 58,
+    Struct = 59,
+    Notnull = 60,
+    Unmanaged = 61,
+    BANG = 62,
+    PLUSPLUS = 63,
+    DASHDASH = 64,
+    True = 65,
+    False = 66,
+    PLUS = 67,
+    DASH = 68,
+    STAR = 69,
+    SLASH = 70,
+    PERCENT = 71,
+    CARET = 72,
+    PIPE = 73,
+    AMP = 74,
+    LTLT = 75,
+    GTGT = 76,
+    EQEQ = 77,
+    BANGEQ = 78,
+    GTEQ = 79,
+    LTEQ = 80,
+    Get = 81,
+    Set = 82,
+    Add = 83,
+    Remove = 84,
+    Init = 85,
+    Enum = 86,
+    Interface = 87,
+    Delegate = 88,
+    Record = 89,
+    Namespace = 90,
+    Var = 91,
+    Managed = 92,
+    Cdecl = 93,
+    Stdcall = 94,
+    Thiscall = 95,
+    Fastcall = 96,
+    PredefinedType = 97,
+    Break = 98,
+    Checked = 99,
+    Unchecked = 100,
+    Continue = 101,
+    Do = 102,
+    While = 103,
+    For = 104,
+    Await = 105,
+    Foreach = 106,
+    Goto = 107,
+    Case = 108,
+    Default = 109,
+    If = 110,
+    Else = 111,
+    Lock = 112,
+    Switch = 113,
+    Not = 114,
+    And = 115,
+    Or = 116,
+    Discard = 117,
+    When = 118,
+    Throw = 119,
+    Try = 120,
+    Catch = 121,
+    Finally = 122,
+    Yield = 123,
+    PLUSEQ = 124,
+    DASHEQ = 125,
+    STAREQ = 126,
+    SLASHEQ = 127,
+    PERCENTEQ = 128,
+    AMPEQ = 129,
+    CARETEQ = 130,
+    PIPEEQ = 131,
+    LTLTEQ = 132,
+    GTGTEQ = 133,
+    QMARKQMARKEQ = 134,
+    Stackalloc = 135,
+    DOLLARDQUOTE = 136,
+    DQUOTE = 137,
+    DOLLARATDQUOTE = 138,
+    ATDOLLARDQUOTE = 139,
+    LBRACELBRACE = 140,
+    InterpolatedStringTextToken1 = 141,
+    InterpolatedVerbatimStringTextToken1 = 142,
+    DQUOTEDQUOTE = 143,
+    InterpolationFormatClauseToken1 = 144,
+    Is = 145,
+    Makeref = 146,
+    DASHGT = 147,
+    From = 148,
+    Join = 149,
+    On = 150,
+    Equals = 151,
+    Into = 152,
+    Let = 153,
+    Orderby = 154,
+    Ascending = 155,
+    Descending = 156,
+    Group = 157,
+    By = 158,
+    Select = 159,
+    DOTDOT = 160,
+    Reftype = 161,
+    Refvalue = 162,
+    Sizeof = 163,
+    Typeof = 164,
+    With = 165,
+    AMPAMP = 166,
+    PIPEPIPE = 167,
+    QMARKQMARK = 168,
+    As = 169,
+    Global2 = 170,
+    SQUOTE = 171,
+    CharacterLiteralToken1 = 172,
+    EscapeSequence = 173,
+    IntegerLiteral = 174,
+    NullLiteral = 175,
+    RealLiteral = 176,
+    StringLiteralToken1 = 177,
+    VerbatimStringLiteral = 178,
+    Comment = 179,
+    Dynamic = 180,
+    Nameof = 181,
+    VoidKeyword = 182,
+    PreprocessorCallToken1 = 183,
+    PreprocessorDirective = 184,
+    PreprocDirectiveEnd = 185,
+    CompilationUnit = 186,
+    GlobalStatement = 187,
+    Declaration = 188,
+    NamespaceMemberDeclaration = 189,
+    TypeDeclaration = 190,
+    ExternAliasDirective = 191,
+    UsingDirective = 192,
+    NameEquals = 193,
+    Name = 194,
+    AliasQualifiedName = 195,
+    SimpleName = 196,
+    GenericName = 197,
+    TypeArgumentList = 198,
+    QualifiedName = 199,
+    AttributeList = 200,
+    AttributeTargetSpecifier = 201,
+    Attribute = 202,
+    AttributeArgumentList = 203,
+    AttributeArgument = 204,
+    GlobalAttributeList = 205,
+    NameColon = 206,
+    EventFieldDeclaration = 207,
+    Modifier = 208,
+    VariableDeclaration = 209,
+    VariableDeclarator = 210,
+    BracketedArgumentList = 211,
+    TuplePattern = 212,
+    Argument = 213,
+    EqualsValueClause = 214,
+    FieldDeclaration = 215,
+    ConstructorDeclaration = 216,
+    ParameterList = 217,
+    FormalParameterList = 218,
+    Parameter = 219,
+    ParameterModifier = 220,
+    ParameterArray = 221,
+    ConstructorInitializer = 222,
+    ArgumentList = 223,
+    Block = 224,
+    ArrowExpressionClause = 225,
+    ConversionOperatorDeclaration = 226,
+    FunctionBody = 227,
+    DestructorDeclaration = 228,
+    MethodDeclaration = 229,
+    ExplicitInterfaceSpecifier = 230,
+    TypeParameterList = 231,
+    TypeParameter = 232,
+    TypeParameterConstraintsClause = 233,
+    TypeParameterConstraint = 234,
+    ConstructorConstraint = 235,
+    TypeConstraint = 236,
+    OperatorDeclaration = 237,
+    OverloadableOperator = 238,
+    EventDeclaration = 239,
+    AccessorList = 240,
+    AccessorDeclaration = 241,
+    IndexerDeclaration = 242,
+    BracketedParameterList = 243,
+    PropertyDeclaration = 244,
+    EnumDeclaration = 245,
+    BaseList = 246,
+    EnumMemberDeclarationList = 247,
+    EnumMemberDeclaration = 248,
+    ClassDeclaration = 249,
+    DeclarationList = 250,
+    InterfaceDeclaration = 251,
+    StructDeclaration = 252,
+    DelegateDeclaration = 253,
+    RecordDeclaration = 254,
+    BaseList2 = 255,
+    PrimaryConstructorBaseType = 256,
+    RecordBase = 257,
+    NamespaceDeclaration = 258,
+    Type = 259,
+    ImplicitType = 260,
+    ArrayType = 261,
+    ArrayRankSpecifier = 262,
+    NullableType = 263,
+    PointerType = 264,
+    FunctionPointerType = 265,
+    FunctionPointerCallingConvention = 266,
+    FunctionPointerUnmanagedCallingConventionList = 267,
+    FunctionPointerUnmanagedCallingConvention = 268,
+    FunctionPointerParameter = 269,
+    TupleType = 270,
+    TupleElement = 271,
+    Statement = 272,
+    BreakStatement = 273,
+    CheckedStatement = 274,
+    ContinueStatement = 275,
+    DoStatement = 276,
+    EmptyStatement = 277,
+    ExpressionStatement = 278,
+    FixedStatement = 279,
+    ForStatement = 280,
+    ForEachStatement = 281,
+    GotoStatement = 282,
+    IfStatement = 283,
+    LabeledStatement = 284,
+    LocalDeclarationStatement = 285,
+    LocalFunctionStatement = 286,
+    LockStatement = 287,
+    ReturnStatement = 288,
+    SwitchStatement = 289,
+    SwitchBody = 290,
+    SwitchSection = 291,
+    CasePatternSwitchLabel = 292,
+    Pattern = 293,
+    TypePattern = 294,
+    ParenthesizedPattern = 295,
+    RelationalPattern = 296,
+    NegatedPattern = 297,
+    BinaryPattern = 298,
+    ConstantPattern = 299,
+    DeclarationPattern = 300,
+    VariableDesignation = 301,
+    ParenthesizedVariableDesignation = 302,
+    RecursivePattern = 303,
+    PositionalPatternClause = 304,
+    Subpattern = 305,
+    PropertyPatternClause = 306,
+    VarPattern = 307,
+    WhenClause = 308,
+    CaseSwitchLabel = 309,
+    DefaultSwitchLabel = 310,
+    ThrowStatement = 311,
+    TryStatement = 312,
+    CatchClause = 313,
+    CatchDeclaration = 314,
+    CatchFilterClause = 315,
+    FinallyClause = 316,
+    UnsafeStatement = 317,
+    UsingStatement = 318,
+    WhileStatement = 319,
+    YieldStatement = 320,
+    AnonymousMethodExpression = 321,
+    LambdaExpression = 322,
+    AnonymousObjectCreationExpression = 323,
+    ImplicitObjectCreationExpression = 324,
+    AnonymousObjectMemberDeclarator = 325,
+    ArrayCreationExpression = 326,
+    InitializerExpression = 327,
+    AssignmentExpression = 328,
+    AssignmentOperator = 329,
+    AwaitExpression = 330,
+    CastExpression = 331,
+    CheckedExpression = 332,
+    ConditionalAccessExpression = 333,
+    ConditionalExpression = 334,
+    DeclarationExpression = 335,
+    DefaultExpression = 336,
+    ElementAccessExpression = 337,
+    ElementBindingExpression = 338,
+    ImplicitArrayCreationExpression = 339,
+    ImplicitStackAllocArrayCreationExpression = 340,
+    BaseExpression = 341,
+    ThisExpression = 342,
+    InterpolatedStringExpression = 343,
+    InterpolatedStringContent = 344,
+    InterpolatedVerbatimStringContent = 345,
+    InterpolatedStringText = 346,
+    InterpolatedVerbatimStringText = 347,
+    Interpolation = 348,
+    InterpolationAlignmentClause = 349,
+    InterpolationFormatClause = 350,
+    InvocationExpression = 351,
+    IsPatternExpression = 352,
+    MakeRefExpression = 353,
+    MemberAccessExpression = 354,
+    MemberBindingExpression = 355,
+    ObjectCreationExpression = 356,
+    ParenthesizedExpression = 357,
+    PostfixUnaryExpression = 358,
+    PrefixUnaryExpression = 359,
+    QueryExpression = 360,
+    FromClause = 361,
+    QueryBody = 362,
+    QueryClause = 363,
+    JoinClause = 364,
+    JoinIntoClause = 365,
+    LetClause = 366,
+    OrderByClause = 367,
+    Ordering = 368,
+    WhereClause = 369,
+    SelectOrGroupClause = 370,
+    GroupClause = 371,
+    SelectClause = 372,
+    QueryContinuation = 373,
+    RangeExpression = 374,
+    RefExpression = 375,
+    RefTypeExpression = 376,
+    RefValueExpression = 377,
+    SizeOfExpression = 378,
+    StackAllocArrayCreationExpression = 379,
+    SwitchExpression = 380,
+    SwitchExpressionArm = 381,
+    ThrowExpression = 382,
+    TupleExpression = 383,
+    TypeOfExpression = 384,
+    WithExpression = 385,
+    WithInitializerExpression = 386,
+    SimpleAssignmentExpression = 387,
+    Expression = 388,
+    BinaryExpression = 389,
+    Identifier = 390,
+    Global = 391,
+    Literal = 392,
+    BooleanLiteral = 393,
+    CharacterLiteral = 394,
+    StringLiteral = 395,
+    ContextualKeywords = 396,
+    PreprocessorCall = 397,
+    CompilationUnitRepeat1 = 398,
+    CompilationUnitRepeat2 = 399,
+    CompilationUnitRepeat3 = 400,
+    CompilationUnitRepeat4 = 401,
+    CompilationUnitRepeat5 = 402,
+    TypeArgumentListRepeat1 = 403,
+    TypeArgumentListRepeat2 = 404,
+    AttributeListRepeat1 = 405,
+    AttributeArgumentListRepeat1 = 406,
+    EventFieldDeclarationRepeat1 = 407,
+    EventFieldDeclarationRepeat2 = 408,
+    VariableDeclarationRepeat1 = 409,
+    BracketedArgumentListRepeat1 = 410,
+    TuplePatternRepeat1 = 411,
+    FormalParameterListRepeat1 = 412,
+    BlockRepeat1 = 413,
+    MethodDeclarationRepeat1 = 414,
+    TypeParameterListRepeat1 = 415,
+    TypeParameterConstraintsClauseRepeat1 = 416,
+    AccessorListRepeat1 = 417,
+    BracketedParameterListRepeat1 = 418,
+    EnumMemberDeclarationListRepeat1 = 419,
+    DeclarationListRepeat1 = 420,
+    RecordBaseRepeat1 = 421,
+    ArrayRankSpecifierRepeat1 = 422,
+    FunctionPointerTypeRepeat1 = 423,
+    FunctionPointerUnmanagedCallingConventionListRepeat1 = 424,
+    TupleTypeRepeat1 = 425,
+    ForStatementRepeat1 = 426,
+    SwitchBodyRepeat1 = 427,
+    SwitchSectionRepeat1 = 428,
+    ParenthesizedVariableDesignationRepeat1 = 429,
+    PositionalPatternClauseRepeat1 = 430,
+    TryStatementRepeat1 = 431,
+    AnonymousObjectCreationExpressionRepeat1 = 432,
+    InterpolatedStringExpressionRepeat1 = 433,
+    InterpolatedStringExpressionRepeat2 = 434,
+    QueryBodyRepeat1 = 435,
+    OrderByClauseRepeat1 = 436,
+    SwitchExpressionRepeat1 = 437,
+    WithInitializerExpressionRepeat1 = 438,
+    StringLiteralRepeat1 = 439,
+    PreprocessorCallRepeat1 = 440,
+    LabelName = 441,
+    Error = 442,
+}
+
+impl Into<&'static str> for Csharp {
+    fn into(self) -> &'static str {
+        match self {
+            Csharp::End => "end",
+            Csharp::IdentifierToken => "_identifier_token",
+            Csharp::Extern => "extern",
+            Csharp::Alias => "alias",
+            Csharp::SEMI => ";",
+            Csharp::Using => "using",
+            Csharp::Static => "static",
+            Csharp::EQ => "=",
+            Csharp::COLONCOLON => "::",
+            Csharp::LT => "<",
+            Csharp::COMMA => ",",
+            Csharp::GT => ">",
+            Csharp::DOT => ".",
+            Csharp::LBRACK => "[",
+            Csharp::RBRACK => "]",
+            Csharp::Field => "field",
+            Csharp::Event => "event",
+            Csharp::Method => "method",
+            Csharp::Param => "param",
+            Csharp::Property => "property",
+            Csharp::Return => "return",
+            Csharp::Type2 => "type",
+            Csharp::COLON => ":",
+            Csharp::LPAREN => "(",
+            Csharp::RPAREN => ")",
+            Csharp::Assembly => "assembly",
+            Csharp::Module => "module",
+            Csharp::Abstract => "abstract",
+            Csharp::Async => "async",
+            Csharp::Const => "const",
+            Csharp::Fixed => "fixed",
+            Csharp::Internal => "internal",
+            Csharp::New => "new",
+            Csharp::Override => "override",
+            Csharp::Partial => "partial",
+            Csharp::Private => "private",
+            Csharp::Protected => "protected",
+            Csharp::Public => "public",
+            Csharp::Readonly => "readonly",
+            Csharp::Ref => "ref",
+            Csharp::Sealed => "sealed",
+            Csharp::Unsafe => "unsafe",
+            Csharp::Virtual => "virtual",
+            Csharp::Volatile => "volatile",
+            Csharp::Out => "out",
+            Csharp::In => "in",
+            Csharp::This => "this",
+            Csharp::Params => "params",
+            Csharp::Base => "base",
+            Csharp::LBRACE => "{",
+            Csharp::RBRACE => "}",
+            Csharp::EQGT => "=>",
+            Csharp::Implicit => "implicit",
+            Csharp::Explicit => "explicit",
+            Csharp::Operator => "operator",
+            Csharp::TILDE => "~",
+            Csharp::Where => "where",
+            Csharp::Class => "class",
+            Csharp::QMARK => "?",
+            Csharp::Struct => "struct",
+            Csharp::Notnull => "notnull",
+            Csharp::Unmanaged => "unmanaged",
+            Csharp::BANG => "!",
+            Csharp::PLUSPLUS => "++",
+            Csharp::DASHDASH => "--",
+            Csharp::True => "true",
+            Csharp::False => "false",
+            Csharp::PLUS => "+",
+            Csharp::DASH => "-",
+            Csharp::STAR => "*",
+            Csharp::SLASH => "/",
+            Csharp::PERCENT => "%",
+            Csharp::CARET => "^",
+            Csharp::PIPE => "|",
+            Csharp::AMP => "&",
+            Csharp::LTLT => "<<",
+            Csharp::GTGT => ">>",
+            Csharp::EQEQ => "==",
+            Csharp::BANGEQ => "!=",
+            Csharp::GTEQ => ">=",
+            Csharp::LTEQ => "<=",
+            Csharp::Get => "get",
+            Csharp::Set => "set",
+            Csharp::Add => "add",
+            Csharp::Remove => "remove",
+            Csharp::Init => "init",
+            Csharp::Enum => "enum",
+            Csharp::Interface => "interface",
+            Csharp::Delegate => "delegate",
+            Csharp::Record => "record",
+            Csharp::Namespace => "namespace",
+            Csharp::Var => "var",
+            Csharp::Managed => "managed",
+            Csharp::Cdecl => "Cdecl",
+            Csharp::Stdcall => "Stdcall",
+            Csharp::Thiscall => "Thiscall",
+            Csharp::Fastcall => "Fastcall",
+            Csharp::PredefinedType => "predefined_type",
+            Csharp::Break => "break",
+            Csharp::Checked => "checked",
+            Csharp::Unchecked => "unchecked",
+            Csharp::Continue => "continue",
+            Csharp::Do => "do",
+            Csharp::While => "while",
+            Csharp::For => "for",
+            Csharp::Await => "await",
+            Csharp::Foreach => "foreach",
+            Csharp::Goto => "goto",
+            Csharp::Case => "case",
+            Csharp::Default => "default",
+            Csharp::If => "if",
+            Csharp::Else => "else",
+            Csharp::Lock => "lock",
+            Csharp::Switch => "switch",
+            Csharp::Not => "not",
+            Csharp::And => "and",
+            Csharp::Or => "or",
+            Csharp::Discard => "discard",
+            Csharp::When => "when",
+            Csharp::Throw => "throw",
+            Csharp::Try => "try",
+            Csharp::Catch => "catch",
+            Csharp::Finally => "finally",
+            Csharp::Yield => "yield",
+            Csharp::PLUSEQ => "+=",
+            Csharp::DASHEQ => "-=",
+            Csharp::STAREQ => "*=",
+            Csharp::SLASHEQ => "/=",
+            Csharp::PERCENTEQ => "%=",
+            Csharp::AMPEQ => "&=",
+            Csharp::CARETEQ => "^=",
+            Csharp::PIPEEQ => "|=",
+            Csharp::LTLTEQ => "<<=",
+            Csharp::GTGTEQ => ">>=",
+            Csharp::QMARKQMARKEQ => "??=",
+            Csharp::Stackalloc => "stackalloc",
+            Csharp::DOLLARDQUOTE => "$\"",
+            Csharp::DQUOTE => "\"",
+            Csharp::DOLLARATDQUOTE => "$@\"",
+            Csharp::ATDOLLARDQUOTE => "@$\"",
+            Csharp::LBRACELBRACE => "{{",
+            Csharp::InterpolatedStringTextToken1 => "interpolated_string_text_token1",
+            Csharp::InterpolatedVerbatimStringTextToken1 => {
+                "interpolated_verbatim_string_text_token1"
+            }
+            Csharp::DQUOTEDQUOTE => "\"\"",
+            Csharp::InterpolationFormatClauseToken1 => "interpolation_format_clause_token1",
+            Csharp::Is => "is",
+            Csharp::Makeref => "__makeref",
+            Csharp::DASHGT => "->",
+            Csharp::From => "from",
+            Csharp::Join => "join",
+            Csharp::On => "on",
+            Csharp::Equals => "equals",
+            Csharp::Into => "into",
+            Csharp::Let => "let",
+            Csharp::Orderby => "orderby",
+            Csharp::Ascending => "ascending",
+            Csharp::Descending => "descending",
+            Csharp::Group => "group",
+            Csharp::By => "by",
+            Csharp::Select => "select",
+            Csharp::DOTDOT => "..",
+            Csharp::Reftype => "__reftype",
+            Csharp::Refvalue => "__refvalue",
+            Csharp::Sizeof => "sizeof",
+            Csharp::Typeof => "typeof",
+            Csharp::With => "with",
+            Csharp::AMPAMP => "&&",
+            Csharp::PIPEPIPE => "||",
+            Csharp::QMARKQMARK => "??",
+            Csharp::As => "as",
+            Csharp::Global2 => "global",
+            Csharp::SQUOTE => "'",
+            Csharp::CharacterLiteralToken1 => "character_literal_token1",
+            Csharp::EscapeSequence => "escape_sequence",
+            Csharp::IntegerLiteral => "integer_literal",
+            Csharp::NullLiteral => "null_literal",
+            Csharp::RealLiteral => "real_literal",
+            Csharp::StringLiteralToken1 => "string_literal_token1",
+            Csharp::VerbatimStringLiteral => "verbatim_string_literal",
+            Csharp::Comment => "comment",
+            Csharp::Dynamic => "dynamic",
+            Csharp::Nameof => "nameof",
+            Csharp::VoidKeyword => "void_keyword",
+            Csharp::PreprocessorCallToken1 => "preprocessor_call_token1",
+            Csharp::PreprocessorDirective => "preprocessor_directive",
+            Csharp::PreprocDirectiveEnd => "_preproc_directive_end",
+            Csharp::CompilationUnit => "compilation_unit",
+            Csharp::GlobalStatement => "global_statement",
+            Csharp::Declaration => "_declaration",
+            Csharp::NamespaceMemberDeclaration => "_namespace_member_declaration",
+            Csharp::TypeDeclaration => "_type_declaration",
+            Csharp::ExternAliasDirective => "extern_alias_directive",
+            Csharp::UsingDirective => "using_directive",
+            Csharp::NameEquals => "name_equals",
+            Csharp::Name => "_name",
+            Csharp::AliasQualifiedName => "alias_qualified_name",
+            Csharp::SimpleName => "_simple_name",
+            Csharp::GenericName => "generic_name",
+            Csharp::TypeArgumentList => "type_argument_list",
+            Csharp::QualifiedName => "qualified_name",
+            Csharp::AttributeList => "attribute_list",
+            Csharp::AttributeTargetSpecifier => "attribute_target_specifier",
+            Csharp::Attribute => "attribute",
+            Csharp::AttributeArgumentList => "attribute_argument_list",
+            Csharp::AttributeArgument => "attribute_argument",
+            Csharp::GlobalAttributeList => "global_attribute_list",
+            Csharp::NameColon => "name_colon",
+            Csharp::EventFieldDeclaration => "event_field_declaration",
+            Csharp::Modifier => "modifier",
+            Csharp::VariableDeclaration => "variable_declaration",
+            Csharp::VariableDeclarator => "variable_declarator",
+            Csharp::BracketedArgumentList => "bracketed_argument_list",
+            Csharp::TuplePattern => "tuple_pattern",
+            Csharp::Argument => "argument",
+            Csharp::EqualsValueClause => "equals_value_clause",
+            Csharp::FieldDeclaration => "field_declaration",
+            Csharp::ConstructorDeclaration => "constructor_declaration",
+            Csharp::ParameterList => "parameter_list",
+            Csharp::FormalParameterList => "_formal_parameter_list",
+            Csharp::Parameter => "parameter",
+            Csharp::ParameterModifier => "parameter_modifier",
+            Csharp::ParameterArray => "parameter_array",
+            Csharp::ConstructorInitializer => "constructor_initializer",
+            Csharp::ArgumentList => "argument_list",
+            Csharp::Block => "block",
+            Csharp::ArrowExpressionClause => "arrow_expression_clause",
+            Csharp::ConversionOperatorDeclaration => "conversion_operator_declaration",
+            Csharp::FunctionBody => "_function_body",
+            Csharp::DestructorDeclaration => "destructor_declaration",
+            Csharp::MethodDeclaration => "method_declaration",
+            Csharp::ExplicitInterfaceSpecifier => "explicit_interface_specifier",
+            Csharp::TypeParameterList => "type_parameter_list",
+            Csharp::TypeParameter => "type_parameter",
+            Csharp::TypeParameterConstraintsClause => "type_parameter_constraints_clause",
+            Csharp::TypeParameterConstraint => "type_parameter_constraint",
+            Csharp::ConstructorConstraint => "constructor_constraint",
+            Csharp::TypeConstraint => "type_constraint",
+            Csharp::OperatorDeclaration => "operator_declaration",
+            Csharp::OverloadableOperator => "_overloadable_operator",
+            Csharp::EventDeclaration => "event_declaration",
+            Csharp::AccessorList => "accessor_list",
+            Csharp::AccessorDeclaration => "accessor_declaration",
+            Csharp::IndexerDeclaration => "indexer_declaration",
+            Csharp::BracketedParameterList => "bracketed_parameter_list",
+            Csharp::PropertyDeclaration => "property_declaration",
+            Csharp::EnumDeclaration => "enum_declaration",
+            Csharp::BaseList => "base_list",
+            Csharp::EnumMemberDeclarationList => "enum_member_declaration_list",
+            Csharp::EnumMemberDeclaration => "enum_member_declaration",
+            Csharp::ClassDeclaration => "class_declaration",
+            Csharp::DeclarationList => "declaration_list",
+            Csharp::InterfaceDeclaration => "interface_declaration",
+            Csharp::StructDeclaration => "struct_declaration",
+            Csharp::DelegateDeclaration => "delegate_declaration",
+            Csharp::RecordDeclaration => "record_declaration",
+            Csharp::BaseList2 => "base_list",
+            Csharp::PrimaryConstructorBaseType => "primary_constructor_base_type",
+            Csharp::RecordBase => "_record_base",
+            Csharp::NamespaceDeclaration => "namespace_declaration",
+            Csharp::Type => "_type",
+            Csharp::ImplicitType => "implicit_type",
+            Csharp::ArrayType => "array_type",
+            Csharp::ArrayRankSpecifier => "array_rank_specifier",
+            Csharp::NullableType => "nullable_type",
+            Csharp::PointerType => "pointer_type",
+            Csharp::FunctionPointerType => "function_pointer_type",
+            Csharp::FunctionPointerCallingConvention => "function_pointer_calling_convention",
+            Csharp::FunctionPointerUnmanagedCallingConventionList => {
+                "function_pointer_unmanaged_calling_convention_list"
+            }
+            Csharp::FunctionPointerUnmanagedCallingConvention => {
+                "function_pointer_unmanaged_calling_convention"
+            }
+            Csharp::FunctionPointerParameter => "function_pointer_parameter",
+            Csharp::TupleType => "tuple_type",
+            Csharp::TupleElement => "tuple_element",
+            Csharp::Statement => "_statement",
+            Csharp::BreakStatement => "break_statement",
+            Csharp::CheckedStatement => "checked_statement",
+            Csharp::ContinueStatement => "continue_statement",
+            Csharp::DoStatement => "do_statement",
+            Csharp::EmptyStatement => "empty_statement",
+            Csharp::ExpressionStatement => "expression_statement",
+            Csharp::FixedStatement => "fixed_statement",
+            Csharp::ForStatement => "for_statement",
+            Csharp::ForEachStatement => "for_each_statement",
+            Csharp::GotoStatement => "goto_statement",
+            Csharp::IfStatement => "if_statement",
+            Csharp::LabeledStatement => "labeled_statement",
+            Csharp::LocalDeclarationStatement => "local_declaration_statement",
+            Csharp::LocalFunctionStatement => "local_function_statement",
+            Csharp::LockStatement => "lock_statement",
+            Csharp::ReturnStatement => "return_statement",
+            Csharp::SwitchStatement => "switch_statement",
+            Csharp::SwitchBody => "switch_body",
+            Csharp::SwitchSection => "switch_section",
+            Csharp::CasePatternSwitchLabel => "case_pattern_switch_label",
+            Csharp::Pattern => "_pattern",
+            Csharp::TypePattern => "type_pattern",
+            Csharp::ParenthesizedPattern => "parenthesized_pattern",
+            Csharp::RelationalPattern => "relational_pattern",
+            Csharp::NegatedPattern => "negated_pattern",
+            Csharp::BinaryPattern => "binary_pattern",
+            Csharp::ConstantPattern => "constant_pattern",
+            Csharp::DeclarationPattern => "declaration_pattern",
+            Csharp::VariableDesignation => "_variable_designation",
+            Csharp::ParenthesizedVariableDesignation => "parenthesized_variable_designation",
+            Csharp::RecursivePattern => "recursive_pattern",
+            Csharp::PositionalPatternClause => "positional_pattern_clause",
+            Csharp::Subpattern => "subpattern",
+            Csharp::PropertyPatternClause => "property_pattern_clause",
+            Csharp::VarPattern => "var_pattern",
+            Csharp::WhenClause => "when_clause",
+            Csharp::CaseSwitchLabel => "case_switch_label",
+            Csharp::DefaultSwitchLabel => "default_switch_label",
+            Csharp::ThrowStatement => "throw_statement",
+            Csharp::TryStatement => "try_statement",
+            Csharp::CatchClause => "catch_clause",
+            Csharp::CatchDeclaration => "catch_declaration",
+            Csharp::CatchFilterClause => "catch_filter_clause",
+            Csharp::FinallyClause => "finally_clause",
+            Csharp::UnsafeStatement => "unsafe_statement",
+            Csharp::UsingStatement => "using_statement",
+            Csharp::WhileStatement => "while_statement",
+            Csharp::YieldStatement => "yield_statement",
+            Csharp::AnonymousMethodExpression => "anonymous_method_expression",
+            Csharp::LambdaExpression => "lambda_expression",
+            Csharp::AnonymousObjectCreationExpression => "anonymous_object_creation_expression",
+            Csharp::ImplicitObjectCreationExpression => "implicit_object_creation_expression",
+            Csharp::AnonymousObjectMemberDeclarator => "_anonymous_object_member_declarator",
+            Csharp::ArrayCreationExpression => "array_creation_expression",
+            Csharp::InitializerExpression => "initializer_expression",
+            Csharp::AssignmentExpression => "assignment_expression",
+            Csharp::AssignmentOperator => "assignment_operator",
+            Csharp::AwaitExpression => "await_expression",
+            Csharp::CastExpression => "cast_expression",
+            Csharp::CheckedExpression => "checked_expression",
+            Csharp::ConditionalAccessExpression => "conditional_access_expression",
+            Csharp::ConditionalExpression => "conditional_expression",
+            Csharp::DeclarationExpression => "declaration_expression",
+            Csharp::DefaultExpression => "default_expression",
+            Csharp::ElementAccessExpression => "element_access_expression",
+            Csharp::ElementBindingExpression => "element_binding_expression",
+            Csharp::ImplicitArrayCreationExpression => "implicit_array_creation_expression",
+            Csharp::ImplicitStackAllocArrayCreationExpression => {
+                "implicit_stack_alloc_array_creation_expression"
+            }
+            Csharp::BaseExpression => "base_expression",
+            Csharp::ThisExpression => "this_expression",
+            Csharp::InterpolatedStringExpression => "interpolated_string_expression",
+            Csharp::InterpolatedStringContent => "_interpolated_string_content",
+            Csharp::InterpolatedVerbatimStringContent => "_interpolated_verbatim_string_content",
+            Csharp::InterpolatedStringText => "interpolated_string_text",
+            Csharp::InterpolatedVerbatimStringText => "interpolated_verbatim_string_text",
+            Csharp::Interpolation => "interpolation",
+            Csharp::InterpolationAlignmentClause => "interpolation_alignment_clause",
+            Csharp::InterpolationFormatClause => "interpolation_format_clause",
+            Csharp::InvocationExpression => "invocation_expression",
+            Csharp::IsPatternExpression => "is_pattern_expression",
+            Csharp::MakeRefExpression => "make_ref_expression",
+            Csharp::MemberAccessExpression => "member_access_expression",
+            Csharp::MemberBindingExpression => "member_binding_expression",
+            Csharp::ObjectCreationExpression => "object_creation_expression",
+            Csharp::ParenthesizedExpression => "parenthesized_expression",
+            Csharp::PostfixUnaryExpression => "postfix_unary_expression",
+            Csharp::PrefixUnaryExpression => "prefix_unary_expression",
+            Csharp::QueryExpression => "query_expression",
+            Csharp::FromClause => "from_clause",
+            Csharp::QueryBody => "_query_body",
+            Csharp::QueryClause => "_query_clause",
+            Csharp::JoinClause => "join_clause",
+            Csharp::JoinIntoClause => "join_into_clause",
+            Csharp::LetClause => "let_clause",
+            Csharp::OrderByClause => "order_by_clause",
+            Csharp::Ordering => "_ordering",
+            Csharp::WhereClause => "where_clause",
+            Csharp::SelectOrGroupClause => "_select_or_group_clause",
+            Csharp::GroupClause => "group_clause",
+            Csharp::SelectClause => "select_clause",
+            Csharp::QueryContinuation => "query_continuation",
+            Csharp::RangeExpression => "range_expression",
+            Csharp::RefExpression => "ref_expression",
+            Csharp::RefTypeExpression => "ref_type_expression",
+            Csharp::RefValueExpression => "ref_value_expression",
+            Csharp::SizeOfExpression => "size_of_expression",
+            Csharp::StackAllocArrayCreationExpression => "stack_alloc_array_creation_expression",
+            Csharp::SwitchExpression => "switch_expression",
+            Csharp::SwitchExpressionArm => "switch_expression_arm",
+            Csharp::ThrowExpression => "throw_expression",
+            Csharp::TupleExpression => "tuple_expression",
+            Csharp::TypeOfExpression => "type_of_expression",
+            Csharp::WithExpression => "with_expression",
+            Csharp::WithInitializerExpression => "with_initializer_expression",
+            Csharp::SimpleAssignmentExpression => "simple_assignment_expression",
+            Csharp::Expression => "_expression",
+            Csharp::BinaryExpression => "binary_expression",
+            Csharp::Identifier => "identifier",
+            Csharp::Global => "global",
+            Csharp::Literal => "_literal",
+            Csharp::BooleanLiteral => "boolean_literal",
+            Csharp::CharacterLiteral => "character_literal",
+            Csharp::StringLiteral => "string_literal",
+            Csharp::ContextualKeywords => "_contextual_keywords",
+            Csharp::PreprocessorCall => "preprocessor_call",
+            Csharp::CompilationUnitRepeat1 => "compilation_unit_repeat1",
+            Csharp::CompilationUnitRepeat2 => "compilation_unit_repeat2",
+            Csharp::CompilationUnitRepeat3 => "compilation_unit_repeat3",
+            Csharp::CompilationUnitRepeat4 => "compilation_unit_repeat4",
+            Csharp::CompilationUnitRepeat5 => "compilation_unit_repeat5",
+            Csharp::TypeArgumentListRepeat1 => "type_argument_list_repeat1",
+            Csharp::TypeArgumentListRepeat2 => "type_argument_list_repeat2",
+            Csharp::AttributeListRepeat1 => "attribute_list_repeat1",
+            Csharp::AttributeArgumentListRepeat1 => "attribute_argument_list_repeat1",
+            Csharp::EventFieldDeclarationRepeat1 => "event_field_declaration_repeat1",
+            Csharp::EventFieldDeclarationRepeat2 => "event_field_declaration_repeat2",
+            Csharp::VariableDeclarationRepeat1 => "variable_declaration_repeat1",
+            Csharp::BracketedArgumentListRepeat1 => "bracketed_argument_list_repeat1",
+            Csharp::TuplePatternRepeat1 => "tuple_pattern_repeat1",
+            Csharp::FormalParameterListRepeat1 => "_formal_parameter_list_repeat1",
+            Csharp::BlockRepeat1 => "block_repeat1",
+            Csharp::MethodDeclarationRepeat1 => "method_declaration_repeat1",
+            Csharp::TypeParameterListRepeat1 => "type_parameter_list_repeat1",
+            Csharp::TypeParameterConstraintsClauseRepeat1 => {
+                "type_parameter_constraints_clause_repeat1"
+            }
+            Csharp::AccessorListRepeat1 => "accessor_list_repeat1",
+            Csharp::BracketedParameterListRepeat1 => "bracketed_parameter_list_repeat1",
+            Csharp::EnumMemberDeclarationListRepeat1 => "enum_member_declaration_list_repeat1",
+            Csharp::DeclarationListRepeat1 => "declaration_list_repeat1",
+            Csharp::RecordBaseRepeat1 => "record_base_repeat1",
+            Csharp::ArrayRankSpecifierRepeat1 => "array_rank_specifier_repeat1",
+            Csharp::FunctionPointerTypeRepeat1 => "function_pointer_type_repeat1",
+            Csharp::FunctionPointerUnmanagedCallingConventionListRepeat1 => {
+                "function_pointer_unmanaged_calling_convention_list_repeat1"
+            }
+            Csharp::TupleTypeRepeat1 => "tuple_type_repeat1",
+            Csharp::ForStatementRepeat1 => "for_statement_repeat1",
+            Csharp::SwitchBodyRepeat1 => "switch_body_repeat1",
+            Csharp::SwitchSectionRepeat1 => "switch_section_repeat1",
+            Csharp::ParenthesizedVariableDesignationRepeat1 => {
+                "parenthesized_variable_designation_repeat1"
+            }
+            Csharp::PositionalPatternClauseRepeat1 => "positional_pattern_clause_repeat1",
+            Csharp::TryStatementRepeat1 => "try_statement_repeat1",
+            Csharp::AnonymousObjectCreationExpressionRepeat1 => {
+                "anonymous_object_creation_expression_repeat1"
+            }
+            Csharp::InterpolatedStringExpressionRepeat1 => "interpolated_string_expression_repeat1",
+            Csharp::InterpolatedStringExpressionRepeat2 => "interpolated_string_expression_repeat2",
+            Csharp::QueryBodyRepeat1 => "_query_body_repeat1",
+            Csharp::OrderByClauseRepeat1 => "order_by_clause_repeat1",
+            Csharp::SwitchExpressionRepeat1 => "switch_expression_repeat1",
+            Csharp::WithInitializerExpressionRepeat1 => "with_initializer_expression_repeat1",
+            Csharp::StringLiteralRepeat1 => "string_literal_repeat1",
+            Csharp::PreprocessorCallRepeat1 => "preprocessor_call_repeat1",
+            Csharp::LabelName => "label_name",
+            Csharp::Error => "ERROR",
+        }
+    }
+}
+
+#[allow(clippy::unreadable_literal)]
+static KEYS: phf::Map<&'static str, Csharp> = ::phf::Map {
+    key: 3213172566270843353,
+    disps: ::phf::Slice::Static(&[
+        (1, 5),
+        (0, 7),
+        (1, 0),
+        (0, 196),
+        (0, 0),
+        (0, 50),
+        (0, 177),
+        (0, 360),
+        (0, 14),
+        (0, 91),
+        (0, 1),
+        (0, 0),
+        (0, 89),
+        (0, 4),
+        (0, 221),
+        (0, 339),
+        (0, 189),
+        (0, 20),
+        (0, 0),
+        (0, 313),
+        (0, 26),
+        (0, 0),
+        (0, 11),
+        (0, 36),
+        (0, 7),
+        (1, 250),
+        (0, 0),
+        (0, 19),
+        (0, 86),
+        (0, 23),
+        (0, 19),
+        (0, 0),
+        (2, 235),
+        (4, 167),
+        (0, 272),
+        (0, 142),
+        (0, 386),
+        (0, 371),
+        (11, 188),
+        (0, 5),
+        (0, 47),
+        (4, 391),
+        (0, 363),
+        (0, 28),
+        (1, 190),
+        (0, 2),
+        (3, 175),
+        (0, 75),
+        (0, 2),
+        (0, 10),
+        (0, 22),
+        (0, 118),
+        (0, 0),
+        (0, 6),
+        (0, 1),
+        (0, 7),
+        (2, 23),
+        (0, 404),
+        (0, 182),
+        (5, 279),
+        (15, 134),
+        (6, 317),
+        (0, 3),
+        (0, 104),
+        (1, 7),
+        (0, 29),
+        (26, 133),
+        (0, 126),
+        (0, 230),
+        (2, 176),
+        (6, 262),
+        (1, 140),
+        (0, 2),
+        (2, 403),
+        (24, 367),
+        (23, 299),
+        (0, 3),
+        (34, 25),
+        (19, 146),
+        (3, 37),
+        (0, 7),
+        (0, 25),
+        (1, 300),
+        (0, 40),
+        (0, 77),
+        (13, 122),
+        (0, 217),
+        (143, 406),
+    ]),
+    entries: ::phf::Slice::Static(&[
+        ("try", Csharp::Try),
+        ("parenthesized_pattern", Csharp::ParenthesizedPattern),
+        ("%=", Csharp::PERCENTEQ),
+        ("qualified_name", Csharp::QualifiedName),
+        ("]", Csharp::RBRACK),
+        ("array_type", Csharp::ArrayType),
+        (
+            "object_creation_expression",
+            Csharp::ObjectCreationExpression,
+        ),
+        (
+            "simple_assignment_expression",
+            Csharp::SimpleAssignmentExpression,
+        ),
+        ("declaration_pattern", Csharp::DeclarationPattern),
+        ("implicit", Csharp::Implicit),
+        ("_type_declaration", Csharp::TypeDeclaration),
+        (
+            "parenthesized_variable_designation_repeat1",
+            Csharp::ParenthesizedVariableDesignationRepeat1,
+        ),
+        ("with_expression", Csharp::WithExpression),
+        ("unmanaged", Csharp::Unmanaged),
+        ("nullable_type", Csharp::NullableType),
+        ("order_by_clause", Csharp::OrderByClause),
+        ("==", Csharp::EQEQ),
+        ("type_argument_list", Csharp::TypeArgumentList),
+        ("if_statement", Csharp::IfStatement),
+        ("yield_statement", Csharp::YieldStatement),
+        ("extern_alias_directive", Csharp::ExternAliasDirective),
+        ("attribute_list_repeat1", Csharp::AttributeListRepeat1),
+        (
+            "type_argument_list_repeat2",
+            Csharp::TypeArgumentListRepeat2,
+        ),
+        ("typeof", Csharp::Typeof),
+        ("subpattern", Csharp::Subpattern),
+        ("return", Csharp::Return),
+        ("null_literal", Csharp::NullLiteral),
+        ("indexer_declaration", Csharp::IndexerDeclaration),
+        ("goto_statement", Csharp::GotoStatement),
+        (
+            "primary_constructor_base_type",
+            Csharp::PrimaryConstructorBaseType,
+        ),
+        ("stackalloc", Csharp::Stackalloc),
+        ("positional_pattern_clause", Csharp::PositionalPatternClause),
+        ("on", Csharp::On),
+        ("type_parameter", Csharp::TypeParameter),
+        ("continue", Csharp::Continue),
+        ("real_literal", Csharp::RealLiteral),
+        ("virtual", Csharp::Virtual),
+        ("override", Csharp::Override),
+        ("throw", Csharp::Throw),
+        (
+            "_formal_parameter_list_repeat1",
+            Csharp::FormalParameterListRepeat1,
+        ),
+        ("foreach", Csharp::Foreach),
+        ("select", Csharp::Select),
+        ("_ordering", Csharp::Ordering),
+        ("remove", Csharp::Remove),
+        ("_function_body", Csharp::FunctionBody),
+        ("^", Csharp::CARET),
+        ("binary_expression", Csharp::BinaryExpression),
+        ("param", Csharp::Param),
+        ("when_clause", Csharp::WhenClause),
+        ("=", Csharp::EQ),
+        ("struct_declaration", Csharp::StructDeclaration),
+        ("catch_declaration", Csharp::CatchDeclaration),
+        ("for_statement_repeat1", Csharp::ForStatementRepeat1),
+        (
+            "interpolated_string_expression_repeat1",
+            Csharp::InterpolatedStringExpressionRepeat1,
+        ),
+        ("ref", Csharp::Ref),
+        ("invocation_expression", Csharp::InvocationExpression),
+        ("__makeref", Csharp::Makeref),
+        ("declaration_expression", Csharp::DeclarationExpression),
+        ("readonly", Csharp::Readonly),
+        ("preprocessor_call_repeat1", Csharp::PreprocessorCallRepeat1),
+        ("switch_statement", Csharp::SwitchStatement),
+        ("variable_declarator", Csharp::VariableDeclarator),
+        ("property_pattern_clause", Csharp::PropertyPatternClause),
+        ("_record_base", Csharp::RecordBase),
+        ("%", Csharp::PERCENT),
+        ("init", Csharp::Init),
+        ("group", Csharp::Group),
+        ("^=", Csharp::CARETEQ),
+        ("member_access_expression", Csharp::MemberAccessExpression),
+        ("let", Csharp::Let),
+        ("lambda_expression", Csharp::LambdaExpression),
+        ("global_statement", Csharp::GlobalStatement),
+        ("interface", Csharp::Interface),
+        ("checked_expression", Csharp::CheckedExpression),
+        ("-", Csharp::DASH),
+        ("alias", Csharp::Alias),
+        ("{", Csharp::LBRACE),
+        ("interpolated_string_text", Csharp::InterpolatedStringText),
+        ("array_creation_expression", Csharp::ArrayCreationExpression),
+        ("??", Csharp::QMARKQMARK),
+        ("size_of_expression", Csharp::SizeOfExpression),
+        ("base_list", Csharp::BaseList),
+        ("attribute", Csharp::Attribute),
+        (
+            "implicit_object_creation_expression",
+            Csharp::ImplicitObjectCreationExpression,
+        ),
+        ("-=", Csharp::DASHEQ),
+        ("is", Csharp::Is),
+        ("for", Csharp::For),
+        ("|", Csharp::PIPE),
+        ("tuple_element", Csharp::TupleElement),
+        (
+            "interpolation_format_clause_token1",
+            Csharp::InterpolationFormatClauseToken1,
+        ),
+        ("global_attribute_list", Csharp::GlobalAttributeList),
+        ("finally_clause", Csharp::FinallyClause),
+        ("prefix_unary_expression", Csharp::PrefixUnaryExpression),
+        ("$\\\"", Csharp::DOLLARDQUOTE),
+        ("[", Csharp::LBRACK),
+        ("nameof", Csharp::Nameof),
+        ("operator", Csharp::Operator),
+        ("constructor_constraint", Csharp::ConstructorConstraint),
+        ("add", Csharp::Add),
+        ("cast_expression", Csharp::CastExpression),
+        ("declaration_list_repeat1", Csharp::DeclarationListRepeat1),
+        ("block_repeat1", Csharp::BlockRepeat1),
+        ("tuple_type", Csharp::TupleType),
+        ("using_statement", Csharp::UsingStatement),
+        ("bracketed_argument_list", Csharp::BracketedArgumentList),
+        (
+            "event_field_declaration_repeat2",
+            Csharp::EventFieldDeclarationRepeat2,
+        ),
+        ("\\\"\\\"", Csharp::DQUOTEDQUOTE),
+        ("const", Csharp::Const),
+        (
+            "local_declaration_statement",
+            Csharp::LocalDeclarationStatement,
+        ),
+        ("modifier", Csharp::Modifier),
+        (
+            "event_field_declaration_repeat1",
+            Csharp::EventFieldDeclarationRepeat1,
+        ),
+        ("event_declaration", Csharp::EventDeclaration),
+        ("__refvalue", Csharp::Refvalue),
+        ("as", Csharp::As),
+        ("compilation_unit_repeat4", Csharp::CompilationUnitRepeat4),
+        ("where_clause", Csharp::WhereClause),
+        (
+            "function_pointer_calling_convention",
+            Csharp::FunctionPointerCallingConvention,
+        ),
+        ("argument_list", Csharp::ArgumentList),
+        ("+", Csharp::PLUS),
+        ("compilation_unit", Csharp::CompilationUnit),
+        (
+            "implicit_stack_alloc_array_creation_expression",
+            Csharp::ImplicitStackAllocArrayCreationExpression,
+        ),
+        ("method_declaration", Csharp::MethodDeclaration),
+        (
+            "anonymous_object_creation_expression",
+            Csharp::AnonymousObjectCreationExpression,
+        ),
+        ("|=", Csharp::PIPEEQ),
+        ("operator_declaration", Csharp::OperatorDeclaration),
+        ("class", Csharp::Class),
+        ("local_function_statement", Csharp::LocalFunctionStatement),
+        ("explicit", Csharp::Explicit),
+        ("assembly", Csharp::Assembly),
+        (
+            "positional_pattern_clause_repeat1",
+            Csharp::PositionalPatternClauseRepeat1,
+        ),
+        ("using", Csharp::Using),
+        ("internal", Csharp::Internal),
+        (
+            "interpolated_verbatim_string_text_token1",
+            Csharp::InterpolatedVerbatimStringTextToken1,
+        ),
+        ("compilation_unit_repeat3", Csharp::CompilationUnitRepeat3),
+        ("private", Csharp::Private),
+        (";", Csharp::SEMI),
+        ("arrow_expression_clause", Csharp::ArrowExpressionClause),
+        ("equals_value_clause", Csharp::EqualsValueClause),
+        (
+            "_interpolated_verbatim_string_content",
+            Csharp::InterpolatedVerbatimStringContent,
+        ),
+        ("conditional_expression", Csharp::ConditionalExpression),
+        ("event", Csharp::Event),
+        ("for_each_statement", Csharp::ForEachStatement),
+        ("$@\\\"", Csharp::DOLLARATDQUOTE),
+        ("constant_pattern", Csharp::ConstantPattern),
+        ("Thiscall", Csharp::Thiscall),
+        ("enum_declaration", Csharp::EnumDeclaration),
+        ("}", Csharp::RBRACE),
+        ("this", Csharp::This),
+        ("parameter_array", Csharp::ParameterArray),
+        ("parameter", Csharp::Parameter),
+        ("var", Csharp::Var),
+        (
+            "conversion_operator_declaration",
+            Csharp::ConversionOperatorDeclaration,
+        ),
+        (
+            "array_rank_specifier_repeat1",
+            Csharp::ArrayRankSpecifierRepeat1,
+        ),
+        ("checked", Csharp::Checked),
+        (
+            "function_pointer_unmanaged_calling_convention_list_repeat1",
+            Csharp::FunctionPointerUnmanagedCallingConventionListRepeat1,
+        ),
+        ("escape_sequence", Csharp::EscapeSequence),
+        ("yield", Csharp::Yield),
+        ("extern", Csharp::Extern),
+        ("_declaration", Csharp::Declaration),
+        ("when", Csharp::When),
+        ("void_keyword", Csharp::VoidKeyword),
+        ("get", Csharp::Get),
+        (
+            "interpolation_format_clause",
+            Csharp::InterpolationFormatClause,
+        ),
+        ("empty_statement", Csharp::EmptyStatement),
+        ("enum_member_declaration", Csharp::EnumMemberDeclaration),
+        ("_type", Csharp::Type),
+        ("->", Csharp::DASHGT),
+        ("make_ref_expression", Csharp::MakeRefExpression),
+        ("!", Csharp::BANG),
+        ("_overloadable_operator", Csharp::OverloadableOperator),
+        (
+            "function_pointer_parameter",
+            Csharp::FunctionPointerParameter,
+        ),
+        ("type_parameter_constraint", Csharp::TypeParameterConstraint),
+        (
+            "enum_member_declaration_list_repeat1",
+            Csharp::EnumMemberDeclarationListRepeat1,
+        ),
+        ("not", Csharp::Not),
+        ("switch_expression_repeat1", Csharp::SwitchExpressionRepeat1),
+        ("{{", Csharp::LBRACELBRACE),
+        ("notnull", Csharp::Notnull),
+        ("ref_expression", Csharp::RefExpression),
+        (
+            "with_initializer_expression",
+            Csharp::WithInitializerExpression,
+        ),
+        (
+            "type_argument_list_repeat1",
+            Csharp::TypeArgumentListRepeat1,
+        ),
+        ("base_expression", Csharp::BaseExpression),
+        ("record_declaration", Csharp::RecordDeclaration),
+        ("namespace", Csharp::Namespace),
+        ("dynamic", Csharp::Dynamic),
+        ("Cdecl", Csharp::Cdecl),
+        ("partial", Csharp::Partial),
+        ("&&", Csharp::AMPAMP),
+        ("this_expression", Csharp::ThisExpression),
+        (",", Csharp::COMMA),
+        ("false", Csharp::False),
+        (
+            "bracketed_parameter_list_repeat1",
+            Csharp::BracketedParameterListRepeat1,
+        ),
+        ("switch_expression_arm", Csharp::SwitchExpressionArm),
+        ("label_name", Csharp::LabelName),
+        ("descending", Csharp::Descending),
+        (
+            "with_initializer_expression_repeat1",
+            Csharp::WithInitializerExpressionRepeat1,
+        ),
+        (
+            "bracketed_argument_list_repeat1",
+            Csharp::BracketedArgumentListRepeat1,
+        ),
+        ("ref_value_expression", Csharp::RefValueExpression),
+        ("property_declaration", Csharp::PropertyDeclaration),
+        ("relational_pattern", Csharp::RelationalPattern),
+        ("var_pattern", Csharp::VarPattern),
+        ("/=", Csharp::SLASHEQ),
+        (
+            "interpolation_alignment_clause",
+            Csharp::InterpolationAlignmentClause,
+        ),
+        (">>=", Csharp::GTGTEQ),
+        ("return_statement", Csharp::ReturnStatement),
+        (")", Csharp::RPAREN),
+        ("finally", Csharp::Finally),
+        ("boolean_literal", Csharp::BooleanLiteral),
+        ("compilation_unit_repeat5", Csharp::CompilationUnitRepeat5),
+        ("~", Csharp::TILDE),
+        ("break_statement", Csharp::BreakStatement),
+        ("bracketed_parameter_list", Csharp::BracketedParameterList),
+        ("accessor_declaration", Csharp::AccessorDeclaration),
+        ("compilation_unit_repeat1", Csharp::CompilationUnitRepeat1),
+        ("character_literal", Csharp::CharacterLiteral),
+        ("record_base_repeat1", Csharp::RecordBaseRepeat1),
+        ("/", Csharp::SLASH),
+        ("sizeof", Csharp::Sizeof),
+        (".", Csharp::DOT),
+        ("tuple_type_repeat1", Csharp::TupleTypeRepeat1),
+        (
+            "variable_declaration_repeat1",
+            Csharp::VariableDeclarationRepeat1,
+        ),
+        ("do", Csharp::Do),
+        ("from", Csharp::From),
+        ("field_declaration", Csharp::FieldDeclaration),
+        ("managed", Csharp::Managed),
+        ("delegate_declaration", Csharp::DelegateDeclaration),
+        ("group_clause", Csharp::GroupClause),
+        ("_contextual_keywords", Csharp::ContextualKeywords),
+        ("static", Csharp::Static),
+        ("catch_clause", Csharp::CatchClause),
+        ("set", Csharp::Set),
+        ("fixed", Csharp::Fixed),
+        ("*", Csharp::STAR),
+        ("volatile", Csharp::Volatile),
+        ("expression_statement", Csharp::ExpressionStatement),
+        ("default", Csharp::Default),
+        ("ascending", Csharp::Ascending),
+        ("assignment_expression", Csharp::AssignmentExpression),
+        ("break", Csharp::Break),
+        ("orderby", Csharp::Orderby),
+        (">", Csharp::GT),
+        ("global", Csharp::Global),
+        ("join_into_clause", Csharp::JoinIntoClause),
+        ("switch", Csharp::Switch),
+        ("class_declaration", Csharp::ClassDeclaration),
+        ("while", Csharp::While),
+        ("parameter_modifier", Csharp::ParameterModifier),
+        ("identifier", Csharp::Identifier),
+        ("equals", Csharp::Equals),
+        ("throw_expression", Csharp::ThrowExpression),
+        ("case_switch_label", Csharp::CaseSwitchLabel),
+        ("async", Csharp::Async),
+        ("unsafe", Csharp::Unsafe),
+        ("tuple_expression", Csharp::TupleExpression),
+        ("default_expression", Csharp::DefaultExpression),
+        ("struct", Csharp::Struct),
+        ("let_clause", Csharp::LetClause),
+        ("in", Csharp::In),
+        ("fixed_statement", Csharp::FixedStatement),
+        ("_statement", Csharp::Statement),
+        ("block", Csharp::Block),
+        (
+            "interpolated_verbatim_string_text",
+            Csharp::InterpolatedVerbatimStringText,
+        ),
+        (
+            "method_declaration_repeat1",
+            Csharp::MethodDeclarationRepeat1,
+        ),
+        ("case_pattern_switch_label", Csharp::CasePatternSwitchLabel),
+        ("case", Csharp::Case),
+        ("with", Csharp::With),
+        ("tuple_pattern", Csharp::TuplePattern),
+        ("attribute_argument", Csharp::AttributeArgument),
+        ("pointer_type", Csharp::PointerType),
+        ("into", Csharp::Into),
+        ("preprocessor_directive", Csharp::PreprocessorDirective),
+        ("interpolation", Csharp::Interpolation),
+        ("_query_clause", Csharp::QueryClause),
+        ("method", Csharp::Method),
+        ("end", Csharp::End),
+        ("and", Csharp::And),
+        (
+            "attribute_target_specifier",
+            Csharp::AttributeTargetSpecifier,
+        ),
+        ("type_parameter_list", Csharp::TypeParameterList),
+        ("property", Csharp::Property),
+        ("name_equals", Csharp::NameEquals),
+        ("parameter_list", Csharp::ParameterList),
+        ("_preproc_directive_end", Csharp::PreprocDirectiveEnd),
+        (
+            "_interpolated_string_content",
+            Csharp::InterpolatedStringContent,
+        ),
+        ("join_clause", Csharp::JoinClause),
+        (
+            "function_pointer_unmanaged_calling_convention_list",
+            Csharp::FunctionPointerUnmanagedCallingConventionList,
+        ),
+        ("query_continuation", Csharp::QueryContinuation),
+        ("switch_body_repeat1", Csharp::SwitchBodyRepeat1),
+        ("for_statement", Csharp::ForStatement),
+        ("string_literal_repeat1", Csharp::StringLiteralRepeat1),
+        ("++", Csharp::PLUSPLUS),
+        ("assignment_operator", Csharp::AssignmentOperator),
+        ("params", Csharp::Params),
+        ("!=", Csharp::BANGEQ),
+        ("\\\"", Csharp::DQUOTE),
+        ("predefined_type", Csharp::PredefinedType),
+        (":", Csharp::COLON),
+        ("default_switch_label", Csharp::DefaultSwitchLabel),
+        ("?", Csharp::QMARK),
+        (
+            "type_parameter_constraints_clause_repeat1",
+            Csharp::TypeParameterConstraintsClauseRepeat1,
+        ),
+        ("declaration_list", Csharp::DeclarationList),
+        ("implicit_type", Csharp::ImplicitType),
+        ("comment", Csharp::Comment),
+        ("element_access_expression", Csharp::ElementAccessExpression),
+        ("sealed", Csharp::Sealed),
+        ("*=", Csharp::STAREQ),
+        ("throw_statement", Csharp::ThrowStatement),
+        ("parenthesized_expression", Csharp::ParenthesizedExpression),
+        ("true", Csharp::True),
+        ("attribute_list", Csharp::AttributeList),
+        ("interface_declaration", Csharp::InterfaceDeclaration),
+        ("where", Csharp::Where),
+        ("type_constraint", Csharp::TypeConstraint),
+        ("+=", Csharp::PLUSEQ),
+        ("namespace_declaration", Csharp::NamespaceDeclaration),
+        ("Stdcall", Csharp::Stdcall),
+        ("compilation_unit_repeat2", Csharp::CompilationUnitRepeat2),
+        ("||", Csharp::PIPEPIPE),
+        (">>", Csharp::GTGT),
+        ("::", Csharp::COLONCOLON),
+        ("labeled_statement", Csharp::LabeledStatement),
+        ("public", Csharp::Public),
+        ("from_clause", Csharp::FromClause),
+        ("protected", Csharp::Protected),
+        ("record", Csharp::Record),
+        ("out", Csharp::Out),
+        (
+            "enum_member_declaration_list",
+            Csharp::EnumMemberDeclarationList,
+        ),
+        ("function_pointer_type", Csharp::FunctionPointerType),
+        ("do_statement", Csharp::DoStatement),
+        ("string_literal", Csharp::StringLiteral),
+        ("'", Csharp::SQUOTE),
+        ("=>", Csharp::EQGT),
+        ("lock", Csharp::Lock),
+        ("(", Csharp::LPAREN),
+        ("..", Csharp::DOTDOT),
+        (
+            "interpolated_string_expression_repeat2",
+            Csharp::InterpolatedStringExpressionRepeat2,
+        ),
+        ("__reftype", Csharp::Reftype),
+        ("constructor_declaration", Csharp::ConstructorDeclaration),
+        ("try_statement", Csharp::TryStatement),
+        ("character_literal_token1", Csharp::CharacterLiteralToken1),
+        ("_name", Csharp::Name),
+        ("member_binding_expression", Csharp::MemberBindingExpression),
+        ("recursive_pattern", Csharp::RecursivePattern),
+        ("switch_body", Csharp::SwitchBody),
+        ("ERROR", Csharp::Error),
+        (
+            "interpolated_string_expression",
+            Csharp::InterpolatedStringExpression,
+        ),
+        ("preprocessor_call_token1", Csharp::PreprocessorCallToken1),
+        ("catch_filter_clause", Csharp::CatchFilterClause),
+        ("initializer_expression", Csharp::InitializerExpression),
+        ("event_field_declaration", Csharp::EventFieldDeclaration),
+        (">=", Csharp::GTEQ),
+        ("unchecked", Csharp::Unchecked),
+        (
+            "conditional_access_expression",
+            Csharp::ConditionalAccessExpression,
+        ),
+        ("_query_body_repeat1", Csharp::QueryBodyRepeat1),
+        ("join", Csharp::Join),
+        ("argument", Csharp::Argument),
+        ("accessor_list_repeat1", Csharp::AccessorListRepeat1),
+        ("while_statement", Csharp::WhileStatement),
+        ("&=", Csharp::AMPEQ),
+        (
+            "parenthesized_variable_designation",
+            Csharp::ParenthesizedVariableDesignation,
+        ),
+        (
+            "function_pointer_unmanaged_calling_convention",
+            Csharp::FunctionPointerUnmanagedCallingConvention,
+        ),
+        ("goto", Csharp::Goto),
+        ("new", Csharp::New),
+        ("alias_qualified_name", Csharp::AliasQualifiedName),
+        ("name_colon", Csharp::NameColon),
+        (
+            "stack_alloc_array_creation_expression",
+            Csharp::StackAllocArrayCreationExpression,
+        ),
+        ("attribute_argument_list", Csharp::AttributeArgumentList),
+        ("<=", Csharp::LTEQ),
+        ("base", Csharp::Base),
+        ("range_expression", Csharp::RangeExpression),
+        ("continue_statement", Csharp::ContinueStatement),
+        (
+            "implicit_array_creation_expression",
+            Csharp::ImplicitArrayCreationExpression,
+        ),
+        (
+            "anonymous_method_expression",
+            Csharp::AnonymousMethodExpression,
+        ),
+        ("negated_pattern", Csharp::NegatedPattern),
+        ("_simple_name", Csharp::SimpleName),
+        ("tuple_pattern_repeat1", Csharp::TuplePatternRepeat1),
+        ("abstract", Csharp::Abstract),
+        ("enum", Csharp::Enum),
+        ("select_clause", Csharp::SelectClause),
+        ("_formal_parameter_list", Csharp::FormalParameterList),
+        ("module", Csharp::Module),
+        ("checked_statement", Csharp::CheckedStatement),
+        ("using_directive", Csharp::UsingDirective),
+        ("delegate", Csharp::Delegate),
+        ("if", Csharp::If),
+        (
+            "type_parameter_list_repeat1",
+            Csharp::TypeParameterListRepeat1,
+        ),
+        (
+            "anonymous_object_creation_expression_repeat1",
+            Csharp::AnonymousObjectCreationExpressionRepeat1,
+        ),
+        ("_literal", Csharp::Literal),
+        ("unsafe_statement", Csharp::UnsafeStatement),
+        ("variable_declaration", Csharp::VariableDeclaration),
+        (
+            "element_binding_expression",
+            Csharp::ElementBindingExpression,
+        ),
+        ("destructor_declaration", Csharp::DestructorDeclaration),
+        ("string_literal_token1", Csharp::StringLiteralToken1),
+        ("lock_statement", Csharp::LockStatement),
+        ("await", Csharp::Await),
+        ("--", Csharp::DASHDASH),
+        ("@$\\\"", Csharp::ATDOLLARDQUOTE),
+        ("preprocessor_call", Csharp::PreprocessorCall),
+        ("discard", Csharp::Discard),
+        ("<<=", Csharp::LTLTEQ),
+        ("<", Csharp::LT),
+        ("try_statement_repeat1", Csharp::TryStatementRepeat1),
+        (
+            "_anonymous_object_member_declarator",
+            Csharp::AnonymousObjectMemberDeclarator,
+        ),
+        ("_select_or_group_clause", Csharp::SelectOrGroupClause),
+        ("switch_section_repeat1", Csharp::SwitchSectionRepeat1),
+        (
+            "explicit_interface_specifier",
+            Csharp::ExplicitInterfaceSpecifier,
+        ),
+        ("binary_pattern", Csharp::BinaryPattern),
+        ("verbatim_string_literal", Csharp::VerbatimStringLiteral),
+        (
+            "function_pointer_type_repeat1",
+            Csharp::FunctionPointerTypeRepeat1,
+        ),
+        ("_variable_designation", Csharp::VariableDesignation),
+        ("Fastcall", Csharp::Fastcall),
+        ("integer_literal", Csharp::IntegerLiteral),
+        ("ref_type_expression", Csharp::RefTypeExpression),
+        ("postfix_unary_expression", Csharp::PostfixUnaryExpression),
+        ("switch_expression", Csharp::SwitchExpression),
+        (
+            "attribute_argument_list_repeat1",
+            Csharp::AttributeArgumentListRepeat1,
+        ),
+        ("??=", Csharp::QMARKQMARKEQ),
+        ("type_of_expression", Csharp::TypeOfExpression),
+        ("await_expression", Csharp::AwaitExpression),
+        ("by", Csharp::By),
+        ("_pattern", Csharp::Pattern),
+        ("accessor_list", Csharp::AccessorList),
+        ("type_pattern", Csharp::TypePattern),
+        ("catch", Csharp::Catch),
+        ("constructor_initializer", Csharp::ConstructorInitializer),
+        ("else", Csharp::Else),
+        ("_identifier_token", Csharp::IdentifierToken),
+        (
+            "interpolated_string_text_token1",
+            Csharp::InterpolatedStringTextToken1,
+        ),
+        ("order_by_clause_repeat1", Csharp::OrderByClauseRepeat1),
+        ("<<", Csharp::LTLT),
+        ("array_rank_specifier", Csharp::ArrayRankSpecifier),
+        ("generic_name", Csharp::GenericName),
+        ("field", Csharp::Field),
+        ("switch_section", Csharp::SwitchSection),
+        ("is_pattern_expression", Csharp::IsPatternExpression),
+        ("_query_body", Csharp::QueryBody),
+        (
+            "_namespace_member_declaration",
+            Csharp::NamespaceMemberDeclaration,
+        ),
+        ("query_expression", Csharp::QueryExpression),
+        ("or", Csharp::Or),
+        ("_expression", Csharp::Expression),
+        ("&", Csharp::AMP),
+        (
+            "type_parameter_constraints_clause",
+            Csharp::TypeParameterConstraintsClause,
+        ),
+    ]),
+};
+
+impl From<&str> for Csharp {
+    #[inline(always)]
+    fn from(key: &str) -> Self {
+        KEYS.get(key).unwrap().clone()
+    }
+}
+
+impl From<u16> for Csharp {
+    #[inline(always)]
+    fn from(x: u16) -> Self {
+        num::FromPrimitive::from_u16(x).unwrap_or(Self::Error)
+    }
+}
+
+// Csharp == u16
+impl PartialEq<u16> for Csharp {
+    #[inline(always)]
+    fn eq(&self, x: &u16) -> bool {
+        *self == Csharp::from(*x)
+    }
+}
+
+// u16 == Csharp
+impl PartialEq<Csharp> for u16 {
+    #[inline(always)]
+    fn eq(&self, x: &Csharp) -> bool {
+        *x == *self
+    }
+}
@@ -4,6 +4,12 @@ pub use language_ccomment::*;
 pub mod language_cpp;
 pub use language_cpp::*;
 
+pub mod language_csharp;
+pub use language_csharp::*;
+
+pub mod language_go;
+pub use language_go::*;
+
 pub mod language_java;
 pub use language_java::*;
 
@@ -43,6 +43,8 @@
 //! - NEXITS: it counts the number of possible exit points
 //!   from a method/function.
 //! - NARGS: it counts the number of arguments of a function/method.
+//! - CK: it is the Chidamber-Kemerer suite of object-oriented metrics
+//!   (WMC, DIT, NOC, CBO, RFC and LCOM) computed for each class.
 
 #[macro_use]
 extern crate lazy_static;
@@ -62,6 +64,9 @@ mod getter;
 mod alterator;
 pub(crate) use alterator::*;
 
+mod error;
+pub use crate::error::*;
+
 mod node;
 pub use crate::node::*;
 
@@ -95,6 +100,12 @@ pub use crate::count::*;
 mod preproc;
 pub use crate::preproc::*;
 
+mod call_graph;
+pub use crate::call_graph::*;
+
+mod diff;
+pub use crate::diff::*;
+
 mod langs;
 pub use crate::langs::*;
 
@@ -37,6 +37,16 @@ macro_rules! get_language {
             tree_sitter_java::language()
         }
     };
+    (tree_sitter_go) => {
+        fn get_language() -> Language {
+            tree_sitter_go::language()
+        }
+    };
+    (tree_sitter_c_sharp) => {
+        fn get_language() -> Language {
+            tree_sitter_c_sharp::language()
+        }
+    };
     (tree_sitter_preproc) => {
         fn get_language() -> Language {
             tree_sitter_preproc::language()
@@ -120,7 +130,7 @@ macro_rules! mk_action {
         /// ```
         /// use std::path::PathBuf;
         ///
-        /// use rust_code_analysis::{action, Callback, LANG, Metrics, MetricsCfg};
+        /// use rust_code_analysis::{action, Callback, LANG, Metrics, MetricsCfg, MetricsOptions};
         ///
         /// let source_code = "int a = 42;";
         /// let language = LANG::Cpp;
@@ -132,6 +142,7 @@ macro_rules! mk_action {
         /// // Configuration options used by the function which computes the metrics
         /// let cfg = MetricsCfg {
         ///     path,
+        ///     options: MetricsOptions::default(),
         /// };
         ///
         /// action::<Metrics>(&language, source_as_vec, &cfg.path.clone(), None, cfg);
@@ -150,6 +161,48 @@ macro_rules! mk_action {
             }
         }
 
+        /// Runs a function, which implements the [`Callback`] trait,
+        /// on a code written in one of the supported languages,
+        /// failing when the code contains syntax errors.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::path::PathBuf;
+        ///
+        /// use rust_code_analysis::{try_action, Error, Metrics, MetricsCfg, MetricsOptions, LANG};
+        ///
+        /// let source_code = "def foo(:\n    pass\n";
+        /// let language = LANG::Python;
+        ///
+        /// // The path to a dummy file used to contain the source code
+        /// let path = PathBuf::from("foo.py");
+        /// let source_as_vec = source_code.as_bytes().to_vec();
+        ///
+        /// // Configuration options used by the function which computes the metrics
+        /// let cfg = MetricsCfg {
+        ///     path,
+        ///     options: MetricsOptions::default(),
+        /// };
+        ///
+        /// let res = try_action::<Metrics>(&language, source_as_vec, &cfg.path.clone(), None, cfg);
+        /// assert!(matches!(res, Err(Error::Parse { .. })));
+        /// ```
+        ///
+        /// [`Callback`]: trait.Callback.html
+        #[inline(always)]
+        pub fn try_action<T: Callback>(lang: &LANG, source: Vec<u8>, path: &PathBuf, pr: Option<Arc<PreprocResults>>, cfg: T::Cfg) -> Result<T::Res, Error> {
+            match lang {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::new(source, path, pr);
+                        check_parse(&parser)?;
+                        Ok(T::call(cfg, &parser))
+                    },
+                )*
+            }
+        }
+
         /// Returns all function spaces data of a code.
         ///
         /// # Examples
@@ -170,11 +223,76 @@ macro_rules! mk_action {
         /// ```
         #[inline(always)]
         pub fn get_function_spaces(lang: &LANG, source: Vec<u8>, path: &PathBuf, pr: Option<Arc<PreprocResults>>) -> Option<FuncSpace> {
+            get_function_spaces_with_options(lang, source, path, pr, &MetricsOptions::default())
+        }
+
+        /// Returns all function spaces data of a code,
+        /// failing when the code contains syntax errors.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::path::PathBuf;
+        ///
+        /// use rust_code_analysis::{try_get_function_spaces, Error, LANG};
+        ///
+        /// let source_code = "def foo():\n    pass\n";
+        /// let language = LANG::Python;
+        ///
+        /// // The path to a dummy file used to contain the source code
+        /// let path = PathBuf::from("foo.py");
+        /// let source_as_vec = source_code.as_bytes().to_vec();
+        ///
+        /// let space = try_get_function_spaces(&language, source_as_vec, &path, None).unwrap();
+        /// assert_eq!(space.spaces.len(), 1);
+        ///
+        /// let source_as_vec = b"def foo(:\n    pass\n".to_vec();
+        /// let res = try_get_function_spaces(&language, source_as_vec, &path, None);
+        /// assert!(matches!(res, Err(Error::Parse { .. })));
+        /// ```
+        #[inline(always)]
+        pub fn try_get_function_spaces(lang: &LANG, source: Vec<u8>, path: &PathBuf, pr: Option<Arc<PreprocResults>>) -> Result<FuncSpace, Error> {
+            match lang {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::new(source, &path, pr);
+                        try_metrics(&parser, &path)
+                    },
+                )*
+            }
+        }
+
+        /// Returns all function spaces data of a code, computed according
+        /// to the given options.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::path::PathBuf;
+        ///
+        /// use rust_code_analysis::{get_function_spaces_with_options, MetricsOptions, LANG};
+        ///
+        /// let source_code = "int a = 42;";
+        /// let language = LANG::Cpp;
+        ///
+        /// // The path to a dummy file used to contain the source code
+        /// let path = PathBuf::from("foo.c");
+        /// let source_as_vec = source_code.as_bytes().to_vec();
+        ///
+        /// let options = MetricsOptions {
+        ///     cognitive_increments: true,
+        ///     ..Default::default()
+        /// };
+        ///
+        /// get_function_spaces_with_options(&language, source_as_vec, &path, None, &options).unwrap();
+        /// ```
+        #[inline(always)]
+        pub fn get_function_spaces_with_options(lang: &LANG, source: Vec<u8>, path: &PathBuf, pr: Option<Arc<PreprocResults>>, options: &MetricsOptions) -> Option<FuncSpace> {
             match lang {
                 $(
                     LANG::$camel => {
                         let parser = $parser::new(source, &path, pr);
-                        metrics(&parser, &path)
+                        metrics_with_options(&parser, &path, options)
                     },
                 )*
             }
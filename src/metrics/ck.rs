@@ -0,0 +1,1004 @@
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::spaces::{FuncSpace, SpaceKind};
+
+use crate::*;
+
+/// The data of a method needed to compute the metrics of its class.
+#[derive(Debug, Clone)]
+struct Method {
+    name: Option<String>,
+    cyclomatic: f64,
+    accessed: HashSet<String>,
+}
+
+/// The `Ck` metric suite.
+///
+/// It contains the object-oriented metrics defined by Chidamber and Kemerer:
+/// each value is computed for every class-like space and then summed up
+/// in the enclosing spaces, except for `DIT` which is the maximum one.
+#[derive(Default, Clone, Debug)]
+pub struct Stats {
+    classes: usize,
+    wmc: f64,
+    dit: usize,
+    noc: usize,
+    cbo: usize,
+    rfc: usize,
+    lcom: usize,
+    is_class: bool,
+    is_method: bool,
+    bases: Vec<String>,
+    fields: HashSet<String>,
+    accessed: HashSet<String>,
+    calls: HashSet<String>,
+    types: HashSet<String>,
+    methods: Vec<Method>,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("ck", 7)?;
+        st.serialize_field("classes", &self.classes())?;
+        st.serialize_field("wmc", &self.wmc())?;
+        st.serialize_field("dit", &self.dit())?;
+        st.serialize_field("noc", &self.noc())?;
+        st.serialize_field("cbo", &self.cbo())?;
+        st.serialize_field("rfc", &self.rfc())?;
+        st.serialize_field("lcom", &self.lcom())?;
+        st.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            classes: f64,
+            wmc: f64,
+            dit: f64,
+            noc: f64,
+            cbo: f64,
+            rfc: f64,
+            lcom: f64,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            classes: values.classes as usize,
+            wmc: values.wmc,
+            dit: values.dit as usize,
+            noc: values.noc as usize,
+            cbo: values.cbo as usize,
+            rfc: values.rfc as usize,
+            lcom: values.lcom as usize,
+            ..Self::default()
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "classes: {}, \
+             wmc: {}, \
+             dit: {}, \
+             noc: {}, \
+             cbo: {}, \
+             rfc: {}, \
+             lcom: {}",
+            self.classes(),
+            self.wmc(),
+            self.dit(),
+            self.noc(),
+            self.cbo(),
+            self.rfc(),
+            self.lcom(),
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Ck` metric suite into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.classes += other.classes;
+        self.wmc += other.wmc;
+        self.dit = self.dit.max(other.dit);
+        self.noc += other.noc;
+        self.cbo += other.cbo;
+        self.rfc += other.rfc;
+        self.lcom += other.lcom;
+
+        // The references of a nested class belong to that class only
+        if !other.is_class {
+            self.fields.extend(other.fields.iter().cloned());
+            self.accessed.extend(other.accessed.iter().cloned());
+            self.calls.extend(other.calls.iter().cloned());
+            self.types.extend(other.types.iter().cloned());
+        }
+    }
+
+    /// Returns the number of classes in a space
+    #[inline(always)]
+    pub fn classes(&self) -> f64 {
+        self.classes as f64
+    }
+
+    /// Returns the `Wmc` metric value
+    ///
+    /// The Weighted Methods per Class is the sum of the cyclomatic
+    /// complexities of the methods of a class.
+    #[inline(always)]
+    pub fn wmc(&self) -> f64 {
+        self.wmc
+    }
+
+    /// Returns the `Dit` metric value
+    ///
+    /// The Depth of Inheritance Tree is the length of the longest path
+    /// from a class to the root of its hierarchy. Only the classes defined
+    /// in the same file are resolved, while an external base class adds
+    /// one level.
+    #[inline(always)]
+    pub fn dit(&self) -> f64 {
+        self.dit as f64
+    }
+
+    /// Returns the `Noc` metric value
+    ///
+    /// The Number Of Children is the number of classes, defined in the
+    /// same file, which directly inherit from a class.
+    #[inline(always)]
+    pub fn noc(&self) -> f64 {
+        self.noc as f64
+    }
+
+    /// Returns the `Cbo` metric value
+    ///
+    /// The Coupling Between Objects is the number of distinct types
+    /// referenced by a class, other than the class itself.
+    #[inline(always)]
+    pub fn cbo(&self) -> f64 {
+        self.cbo as f64
+    }
+
+    /// Returns the `Rfc` metric value
+    ///
+    /// The Response For a Class is the number of distinct methods which
+    /// can be executed in response to a message received by a class:
+    /// its own methods together with the methods they call.
+    #[inline(always)]
+    pub fn rfc(&self) -> f64 {
+        self.rfc as f64
+    }
+
+    /// Returns the `Lcom` metric value
+    ///
+    /// The Lack of Cohesion in Methods is the number of method pairs which
+    /// do not share any field minus the number of pairs which share at least
+    /// one, or zero when the difference is negative.
+    #[inline(always)]
+    pub fn lcom(&self) -> f64 {
+        self.lcom as f64
+    }
+
+    pub(crate) fn add_method(&mut self, name: Option<&str>, cyclomatic: f64, method: &Stats) {
+        if method.is_method {
+            self.methods.push(Method {
+                name: name.map(|name| name.to_string()),
+                cyclomatic,
+                accessed: method.accessed.clone(),
+            });
+        }
+    }
+
+    pub(crate) fn finalize(&mut self, kind: SpaceKind, name: Option<&str>) {
+        if self.is_class || !is_class_kind(kind) {
+            return;
+        }
+        self.is_class = true;
+        self.classes += 1;
+
+        self.wmc += self.methods.iter().map(|m| m.cyclomatic).sum::<f64>();
+
+        let mut response: HashSet<&str> = self.calls.iter().map(|c| c.as_str()).collect();
+        response.extend(self.methods.iter().filter_map(|m| m.name.as_deref()));
+        self.rfc += response.len();
+
+        self.cbo += self
+            .types
+            .iter()
+            .chain(self.bases.iter())
+            .filter(|t| Some(t.as_str()) != name)
+            .collect::<HashSet<_>>()
+            .len();
+
+        let used: Vec<HashSet<&String>> = self
+            .methods
+            .iter()
+            .map(|m| m.accessed.intersection(&self.fields).collect())
+            .collect();
+        let mut disjoint: usize = 0;
+        let mut shared = 0;
+        for (i, first) in used.iter().enumerate() {
+            for second in &used[i + 1..] {
+                if first.is_disjoint(second) {
+                    disjoint += 1;
+                } else {
+                    shared += 1;
+                }
+            }
+        }
+        self.lcom += disjoint.saturating_sub(shared);
+
+        self.methods.clear();
+    }
+}
+
+#[inline(always)]
+fn is_class_kind(kind: SpaceKind) -> bool {
+    matches!(
+        kind,
+        SpaceKind::Class
+            | SpaceKind::Struct
+            | SpaceKind::Interface
+            | SpaceKind::Enum
+            | SpaceKind::Record
+            | SpaceKind::Trait
+            | SpaceKind::Impl
+    )
+}
+
+fn collect_bases(space: &FuncSpace, bases: &mut HashMap<String, Vec<String>>) {
+    let ck = &space.metrics.ck;
+    if ck.is_class {
+        if let Some(name) = &space.name {
+            bases
+                .entry(name.clone())
+                .or_default()
+                .extend(ck.bases.iter().cloned());
+        }
+    }
+    for space in &space.spaces {
+        collect_bases(space, bases);
+    }
+}
+
+fn depth(name: &str, bases: &HashMap<String, Vec<String>>, seen: &mut HashSet<String>) -> usize {
+    let parents = match bases.get(name) {
+        Some(parents) if !parents.is_empty() => parents,
+        _ => return 0,
+    };
+    seen.insert(name.to_string());
+    let depth = parents
+        .iter()
+        .map(|parent| {
+            if seen.contains(parent) {
+                1
+            } else {
+                1 + depth(parent, bases, seen)
+            }
+        })
+        .max()
+        .unwrap_or(0);
+    seen.remove(name);
+    depth
+}
+
+fn set_inheritance(
+    space: &mut FuncSpace,
+    bases: &HashMap<String, Vec<String>>,
+    children: &HashMap<&str, usize>,
+) -> (usize, usize) {
+    let (mut dit, mut noc) = match (&space.name, space.metrics.ck.is_class) {
+        (Some(name), true) => (
+            depth(name, bases, &mut HashSet::new()),
+            children.get(name.as_str()).copied().unwrap_or(0),
+        ),
+        _ => (0, 0),
+    };
+    for space in space.spaces.iter_mut() {
+        let (child_dit, child_noc) = set_inheritance(space, bases, children);
+        dit = dit.max(child_dit);
+        noc += child_noc;
+    }
+    space.metrics.ck.dit = dit;
+    space.metrics.ck.noc = noc;
+    (dit, noc)
+}
+
+/// Computes the `Dit` and `Noc` metrics of all the classes contained
+/// in a space, since they depend on the whole inheritance graph of a file.
+pub(crate) fn compute_inheritance(space: &mut FuncSpace) {
+    let mut bases = HashMap::new();
+    collect_bases(space, &mut bases);
+
+    let mut children = HashMap::new();
+    for parents in bases.values() {
+        for parent in parents.iter().collect::<HashSet<_>>() {
+            *children.entry(parent.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    set_inheritance(space, &bases, &children);
+}
+
+#[inline(always)]
+fn get_text(node: &Node, code: &[u8]) -> Option<String> {
+    let code = &code[node.object().start_byte()..node.object().end_byte()];
+    std::str::from_utf8(code).ok().map(|text| text.to_string())
+}
+
+#[inline(always)]
+fn insert(set: &mut HashSet<String>, node: Option<tree_sitter::Node>, code: &[u8]) {
+    if let Some(text) = node.and_then(|node| get_text(&Node::new(node), code)) {
+        set.insert(text);
+    }
+}
+
+#[inline(always)]
+fn add_field(stats: &mut Stats, node: Option<tree_sitter::Node>, code: &[u8]) {
+    if let Some(text) = node.and_then(|node| get_text(&Node::new(node), code)) {
+        stats.fields.insert(text.clone());
+        stats.accessed.insert(text);
+    }
+}
+
+#[inline(always)]
+fn add_base(stats: &mut Stats, node: Option<tree_sitter::Node>, code: &[u8]) {
+    if let Some(text) = node.and_then(|node| get_text(&Node::new(node), code)) {
+        stats.bases.push(text);
+    }
+}
+
+#[inline(always)]
+fn is_callee(node: &Node) -> bool {
+    node.object()
+        .parent()
+        .and_then(|parent| parent.child_by_field_name("function"))
+        == Some(node.object())
+}
+
+#[doc(hidden)]
+pub trait Ck
+where
+    Self: Checker,
+{
+    fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+}
+
+impl Ck for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::*;
+
+        match node.object().kind_id().into() {
+            FunctionDefinition => {
+                stats.is_method = true;
+            }
+            ClassDefinition => {
+                if let Some(superclasses) = node.object().child_by_field_name("superclasses") {
+                    Node::new(superclasses).act_on_child(
+                        &mut |n| match n.object().kind_id().into() {
+                            Identifier if get_text(n, code).as_deref() != Some("object") => {
+                                add_base(stats, Some(n.object()), code);
+                            }
+                            Attribute => {
+                                add_base(stats, n.object().child_by_field_name("attribute"), code);
+                            }
+                            _ => {}
+                        },
+                    );
+                }
+                // Class attributes are fields too
+                if let Some(body) = node.object().child_by_field_name("body") {
+                    Node::new(body).act_on_child(&mut |n| {
+                        if let Some(assignment) = n.first_child(|id| id == Assignment) {
+                            if let Some(left) = assignment.object().child_by_field_name("left") {
+                                if left.kind_id() == Identifier {
+                                    stats.fields.extend(get_text(&Node::new(left), code));
+                                }
+                            }
+                        }
+                    });
+                }
+            }
+            Attribute => {
+                let object = node.object().child_by_field_name("object");
+                if !is_callee(node)
+                    && matches!(object, Some(object) if object.kind_id() == Identifier
+                        && &code[object.start_byte()..object.end_byte()] == b"self")
+                {
+                    add_field(stats, node.object().child_by_field_name("attribute"), code);
+                }
+            }
+            Call => {
+                if let Some(function) = node.object().child_by_field_name("function") {
+                    match function.kind_id().into() {
+                        Identifier => insert(&mut stats.calls, Some(function), code),
+                        Attribute => insert(
+                            &mut stats.calls,
+                            function.child_by_field_name("attribute"),
+                            code,
+                        ),
+                        _ => {}
+                    }
+                }
+            }
+            Type => {
+                node.act_on_node(&mut |n| {
+                    if n.object().kind_id() == Identifier {
+                        insert(&mut stats.types, Some(n.object()), code);
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Ck for RustCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Rust::*;
+
+        match node.object().kind_id().into() {
+            FunctionItem => {
+                stats.is_method = true;
+            }
+            FieldExpression => {
+                let value = node.object().child_by_field_name("value");
+                if !is_callee(node) && matches!(value, Some(value) if value.kind_id() == Zelf) {
+                    add_field(stats, node.object().child_by_field_name("field"), code);
+                }
+            }
+            CallExpression => {
+                if let Some(function) = node.object().child_by_field_name("function") {
+                    match function.kind_id().into() {
+                        Identifier => insert(&mut stats.calls, Some(function), code),
+                        FieldExpression => insert(
+                            &mut stats.calls,
+                            function.child_by_field_name("field"),
+                            code,
+                        ),
+                        ScopedIdentifier => {
+                            insert(&mut stats.calls, function.child_by_field_name("name"), code)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            TypeIdentifier => {
+                insert(&mut stats.types, Some(node.object()), code);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Ck for CppCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Cpp::*;
+
+        match node.object().kind_id().into() {
+            FunctionDefinition | FunctionDefinition2 | FunctionDefinition3 => {
+                stats.is_method = true;
+            }
+            BaseClassClause => {
+                node.act_on_child(&mut |n| match n.object().kind_id().into() {
+                    TypeIdentifier => add_base(stats, Some(n.object()), code),
+                    ScopedTypeIdentifier => {
+                        add_base(stats, n.object().child_by_field_name("name"), code)
+                    }
+                    _ => {}
+                });
+            }
+            FieldDeclaration => {
+                node.act_on_child(&mut |n| match n.object().kind_id().into() {
+                    FieldIdentifier => {
+                        stats.fields.extend(get_text(n, code));
+                    }
+                    PointerDeclarator | ReferenceDeclarator | ArrayDeclarator => {
+                        if let Some(field) = n.first_occurence(|id| id == FieldIdentifier) {
+                            stats.fields.extend(get_text(&field, code));
+                        }
+                    }
+                    _ => {}
+                });
+            }
+            FieldExpression => {
+                let argument = node.object().child_by_field_name("argument");
+                if !is_callee(node)
+                    && matches!(argument, Some(argument) if argument.kind_id() == This)
+                {
+                    insert(
+                        &mut stats.accessed,
+                        node.object().child_by_field_name("field"),
+                        code,
+                    );
+                }
+            }
+            Identifier => {
+                // A member can be used without `this`
+                insert(&mut stats.accessed, Some(node.object()), code);
+            }
+            CallExpression => {
+                if let Some(function) = node.object().child_by_field_name("function") {
+                    match function.kind_id().into() {
+                        Identifier => insert(&mut stats.calls, Some(function), code),
+                        FieldExpression => insert(
+                            &mut stats.calls,
+                            function.child_by_field_name("field"),
+                            code,
+                        ),
+                        ScopedIdentifier => {
+                            insert(&mut stats.calls, function.child_by_field_name("name"), code)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            TypeIdentifier => {
+                insert(&mut stats.types, Some(node.object()), code);
+            }
+            _ => {}
+        }
+    }
+}
+
+macro_rules! js_ck {
+    ($lang:ident, $heritage:ident $(, $type:ident)?) => {
+        fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+            use $lang::*;
+
+            match node.object().kind_id().into() {
+                MethodDefinition => {
+                    stats.is_method = true;
+                }
+                $heritage => {
+                    add_base(stats, node.object().named_child(0), code);
+                }
+                PublicFieldDefinition => {
+                    if let Some(field) = node.first_child(|id| id == PropertyIdentifier) {
+                        stats.fields.extend(get_text(&field, code));
+                    }
+                }
+                MemberExpression => {
+                    let object = node.object().child_by_field_name("object");
+                    if !is_callee(node) && matches!(object, Some(object) if object.kind_id() == This) {
+                        add_field(stats, node.object().child_by_field_name("property"), code);
+                    }
+                }
+                CallExpression => {
+                    if let Some(function) = node.object().child_by_field_name("function") {
+                        match function.kind_id().into() {
+                            Identifier => insert(&mut stats.calls, Some(function), code),
+                            MemberExpression => insert(
+                                &mut stats.calls,
+                                function.child_by_field_name("property"),
+                                code,
+                            ),
+                            _ => {}
+                        }
+                    }
+                }
+                NewExpression => {
+                    insert(
+                        &mut stats.types,
+                        node.object().child_by_field_name("constructor"),
+                        code,
+                    );
+                }
+                $(
+                    $type => {
+                        insert(&mut stats.types, Some(node.object()), code);
+                    }
+                )?
+                _ => {}
+            }
+        }
+    };
+}
+
+impl Ck for MozjsCode {
+    js_ck!(Mozjs, ClassHeritage);
+}
+
+impl Ck for JavascriptCode {
+    js_ck!(Javascript, ClassHeritage);
+}
+
+impl Ck for TypescriptCode {
+    js_ck!(Typescript, ExtendsClause, TypeIdentifier);
+}
+
+impl Ck for TsxCode {
+    js_ck!(Tsx, ExtendsClause, TypeIdentifier);
+}
+
+impl Ck for JavaCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Java::*;
+
+        fn get_type<'a>(node: tree_sitter::Node<'a>) -> Option<tree_sitter::Node<'a>> {
+            match node.kind_id().into() {
+                TypeIdentifier => Some(node),
+                GenericType => node.named_child(0).and_then(get_type),
+                ScopedTypeIdentifier => node
+                    .named_child(node.named_child_count().saturating_sub(1))
+                    .and_then(get_type),
+                _ => None,
+            }
+        }
+
+        match node.object().kind_id().into() {
+            MethodDeclaration | ConstructorDeclaration => {
+                stats.is_method = true;
+            }
+            Superclass => {
+                add_base(stats, node.object().named_child(0).and_then(get_type), code);
+            }
+            ExtendsInterfaces => {
+                if let Some(list) = node.first_child(|id| id == InterfaceTypeList) {
+                    list.act_on_child(&mut |n| add_base(stats, get_type(n.object()), code));
+                }
+            }
+            FieldDeclaration => {
+                node.act_on_child(&mut |n| {
+                    if n.object().kind_id() == VariableDeclarator {
+                        insert(
+                            &mut stats.fields,
+                            n.object().child_by_field_name("name"),
+                            code,
+                        );
+                    }
+                });
+            }
+            FieldAccess => {
+                let object = node.object().child_by_field_name("object");
+                if matches!(object, Some(object) if object.kind_id() == This) {
+                    insert(
+                        &mut stats.accessed,
+                        node.object().child_by_field_name("field"),
+                        code,
+                    );
+                }
+            }
+            Identifier => {
+                // A field can be used without `this`
+                if let Some(parent) = node.object().parent() {
+                    let skip = match parent.kind_id().into() {
+                        FieldAccess => true,
+                        MethodInvocation => {
+                            parent.child_by_field_name("name") == Some(node.object())
+                        }
+                        _ => false,
+                    };
+                    if !skip {
+                        insert(&mut stats.accessed, Some(node.object()), code);
+                    }
+                }
+            }
+            MethodInvocation => {
+                insert(
+                    &mut stats.calls,
+                    node.object().child_by_field_name("name"),
+                    code,
+                );
+            }
+            TypeIdentifier => {
+                insert(&mut stats.types, Some(node.object()), code);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Ck for CsharpCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Csharp::*;
+
+        fn get_type<'a>(node: tree_sitter::Node<'a>) -> Option<tree_sitter::Node<'a>> {
+            match node.kind_id().into() {
+                Identifier => Some(node),
+                GenericName => node.named_child(0).and_then(get_type),
+                // The last part of a qualified name is the name of the type
+                QualifiedName => node
+                    .named_child(node.named_child_count().saturating_sub(1))
+                    .and_then(get_type),
+                _ => None,
+            }
+        }
+
+        fn is_type(node: tree_sitter::Node) -> bool {
+            let parent = match node.parent() {
+                Some(parent) => parent,
+                None => return false,
+            };
+            match parent.kind_id().into() {
+                BaseList | BaseList2 | TypeArgumentList => true,
+                GenericName => is_type(parent),
+                QualifiedName => {
+                    parent.named_child(parent.named_child_count().saturating_sub(1)) == Some(node)
+                        && is_type(parent)
+                }
+                _ => parent.child_by_field_name("type") == Some(node),
+            }
+        }
+
+        match node.object().kind_id().into() {
+            // Property accessors are not considered as methods,
+            // since a property is used like a field
+            MethodDeclaration
+            | ConstructorDeclaration
+            | DestructorDeclaration
+            | OperatorDeclaration
+            | ConversionOperatorDeclaration => {
+                stats.is_method = true;
+            }
+            BaseList | BaseList2 => {
+                node.act_on_child(&mut |n| add_base(stats, get_type(n.object()), code));
+            }
+            FieldDeclaration => {
+                if let Some(declaration) = node.first_child(|id| id == VariableDeclaration) {
+                    declaration.act_on_child(&mut |n| {
+                        if n.object().kind_id() == VariableDeclarator {
+                            if let Some(name) = n.first_child(|id| id == Identifier) {
+                                stats.fields.extend(get_text(&name, code));
+                            }
+                        }
+                    });
+                }
+            }
+            PropertyDeclaration => {
+                // Auto-implemented properties hold a field
+                insert(
+                    &mut stats.fields,
+                    node.object().child_by_field_name("name"),
+                    code,
+                );
+            }
+            Identifier => {
+                if is_type(node.object()) {
+                    insert(&mut stats.types, Some(node.object()), code);
+                } else if let Some(parent) = node.object().parent() {
+                    // A field can be used without `this`
+                    let skip = match parent.kind_id().into() {
+                        MemberAccessExpression => {
+                            parent.child_by_field_name("name") == Some(node.object())
+                                && !matches!(
+                                    parent.child_by_field_name("expression"),
+                                    Some(expression) if expression.kind_id() == ThisExpression
+                                )
+                        }
+                        _ => is_callee(node),
+                    };
+                    if !skip {
+                        insert(&mut stats.accessed, Some(node.object()), code);
+                    }
+                }
+            }
+            InvocationExpression => {
+                if let Some(function) = node.object().child_by_field_name("function") {
+                    let name = match function.kind_id().into() {
+                        MemberAccessExpression => function.child_by_field_name("name"),
+                        _ => Some(function),
+                    };
+                    insert(&mut stats.calls, name.and_then(get_type), code);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Ck for GoCode {}
+impl Ck for PreprocCode {}
+impl Ck for CcommentCode {}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn java_ck() {
+        check_metrics!(
+            "class A {
+                 private int x;
+                 private int y;
+                 int getX() { return this.x; }
+                 int getY() {
+                     if (y > 0) {
+                         return y;
+                     }
+                     return 0;
+                 }
+                 void reset() { x = 0; y = 0; }
+             }
+             class B extends A {
+                 private Foo foo = new Foo();
+                 void run(Bar bar) { foo.go(bar); getX(); }
+             }
+             class C extends B {}",
+            "foo.java",
+            JavaParser,
+            ck,
+            [
+                (classes, 3, usize),
+                (wmc, 5, usize),
+                (dit, 2, usize),
+                (noc, 2, usize),
+                (cbo, 4, usize),
+                (rfc, 6, usize),
+                (lcom, 0, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn java_ck_lcom() {
+        check_metrics!(
+            "class A {
+                 int x;
+                 int y;
+                 int z;
+                 int a() { return x; }
+                 int b() { return y; }
+                 int c() { return z; }
+                 int d() { return x + y; }
+             }",
+            "foo.java",
+            JavaParser,
+            ck,
+            [
+                (classes, 1, usize),
+                (wmc, 4, usize),
+                (dit, 0, usize),
+                (noc, 0, usize),
+                (cbo, 0, usize),
+                (rfc, 4, usize),
+                (lcom, 2, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_ck() {
+        check_metrics!(
+            "class A : IFoo {
+                 int x;
+                 int Y { get; set; }
+                 int Get() { return this.x; }
+                 void Set(Bar bar) { x = bar.Value; Y = 1; Log(); }
+             }",
+            "foo.cs",
+            CsharpParser,
+            ck,
+            [
+                (classes, 1, usize),
+                (wmc, 2, usize),
+                (dit, 1, usize),
+                (noc, 0, usize),
+                (cbo, 2, usize),
+                (rfc, 3, usize),
+                (lcom, 0, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_ck_qualified_types() {
+        check_metrics!(
+            "class A : Base.IFoo<int> {
+                 Foo.Bar Get() { return null; }
+                 List<Baz> All() { return null; }
+             }",
+            "foo.cs",
+            CsharpParser,
+            ck,
+            [
+                (classes, 1, usize),
+                (dit, 1, usize),
+                (cbo, 4, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn python_ck() {
+        check_metrics!(
+            "class A:
+                 def __init__(self):
+                     self.x = 0
+                     self.y = 0
+                 def get_x(self):
+                     return self.x
+                 def get_y(self):
+                     return self.y
+             class B(A, object):
+                 def run(self, foo: Foo):
+                     self.get_x()
+                     print(foo)",
+            "foo.py",
+            PythonParser,
+            ck,
+            [
+                (classes, 2, usize),
+                (wmc, 4, usize),
+                (dit, 1, usize),
+                (noc, 1, usize),
+                (cbo, 2, usize),
+                (rfc, 6, usize),
+                (lcom, 0, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_ck() {
+        check_metrics!(
+            "impl Point {
+                 fn x(&self) -> Coord { self.x }
+                 fn y(&self) -> Coord { self.y }
+                 fn norm(&self) -> f64 {
+                     if self.x.is_zero() {
+                         self.y.abs()
+                     } else {
+                         self.x.hypot(self.y)
+                     }
+                 }
+             }",
+            "foo.rs",
+            RustParser,
+            ck,
+            [
+                (classes, 1, usize),
+                (wmc, 4, usize),
+                (dit, 0, usize),
+                (noc, 0, usize),
+                (cbo, 1, usize),
+                (rfc, 6, usize),
+                (lcom, 0, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn typescript_ck() {
+        check_metrics!(
+            "class A {
+                 x = 0;
+                 y: Foo;
+                 getX(): number { return this.x; }
+                 getY(): Foo { return this.y; }
+             }
+             class B extends A {
+                 run() { return new Bar(this.getX()); }
+             }",
+            "foo.ts",
+            TypescriptParser,
+            ck,
+            [
+                (classes, 2, usize),
+                (wmc, 3, usize),
+                (dit, 1, usize),
+                (noc, 1, usize),
+                (cbo, 3, usize),
+                (rfc, 4, usize),
+                (lcom, 1, usize)
+            ]
+        );
+    }
+}
@@ -1,18 +1,58 @@
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
+use std::collections::HashSet;
 use std::fmt;
 
+use super::average_count;
+
+use crate::call_graph::FileFunctions;
 use crate::checker::Checker;
 use crate::*;
 
-// TODO: Find a way to increment the cognitive complexity value
-// for recursive code. For some kind of langauges, such as C++, it is pretty
-// hard to detect, just parsing the code, if a determined function is recursive
-// because the call graph of a function is solved at runtime.
-// So a possible solution could be searching for a crate which implements
-// a light language interpreter, computing the call graph, and then detecting
-// if there are cycles. At this point, it is possible to figure out if a
-// function is recursive or not.
+// Recursion is detected per file: a call is resolved just comparing its name
+// with the names of the functions defined in the same file, so calls
+// to functions defined elsewhere, or solved at runtime, are not considered.
+// A call on another object, e.g. `other.equals(o)`, is never resolved,
+// while a call on the caller itself, e.g. `self.f()`, is resolved only
+// against the methods of the same class. For this reason, overloads,
+// and unqualified calls to the methods of different classes,
+// cannot be distinguished.
+
+/// An increment of the `Cognitive Complexity` metric.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Increment {
+    /// The kind of the node which causes the increment
+    pub kind: String,
+    /// The first line of the node
+    pub start_line: usize,
+    /// The first column of the node
+    pub start_column: usize,
+    /// The last line of the node
+    pub end_line: usize,
+    /// The column following the last character of the node
+    pub end_column: usize,
+    /// The nesting level which contributes to the increment
+    pub nesting: usize,
+    /// The increment amount
+    pub increment: usize,
+}
+
+impl Increment {
+    fn new(node: &Node, nesting: usize, increment: usize) -> Self {
+        let start = node.object().start_position();
+        let end = node.object().end_position();
+        Self {
+            kind: node.object().kind().to_string(),
+            start_line: start.row + 1,
+            start_column: start.column + 1,
+            end_line: end.row + 1,
+            end_column: end.column + 1,
+            nesting,
+            increment,
+        }
+    }
+}
 
 /// The `Cognitive Complexity` metric.
 #[derive(Debug, Clone)]
@@ -21,6 +61,10 @@ pub struct Stats {
     nesting: usize,
     total_space_functions: usize,
     boolean_seq: BoolSequence,
+    increments: Option<Vec<Increment>>,
+    // The increment of each call of the space, which is added
+    // when the call makes the function recursive
+    call_increments: Vec<Increment>,
 }
 
 impl Default for Stats {
@@ -30,6 +74,8 @@ impl Default for Stats {
             nesting: 0,
             total_space_functions: 1,
             boolean_seq: BoolSequence::default(),
+            increments: None,
+            call_increments: Vec::new(),
         }
     }
 }
@@ -39,13 +85,40 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("cognitive", 2)?;
+        let len = if self.increments.is_some() { 3 } else { 2 };
+        let mut st = serializer.serialize_struct("cognitive", len)?;
         st.serialize_field("sum", &self.cognitive())?;
         st.serialize_field("average", &self.cognitive_average())?;
+        if let Some(increments) = &self.increments {
+            st.serialize_field("increments", increments)?;
+        }
         st.end()
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            sum: f64,
+            average: Option<f64>,
+            #[serde(default)]
+            increments: Option<Vec<Increment>>,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            structural: values.sum as usize,
+            total_space_functions: average_count(values.sum, values.average).unwrap_or(1),
+            increments: values.increments,
+            ..Self::default()
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -78,9 +151,116 @@ impl Stats {
         self.cognitive() / self.total_space_functions as f64
     }
 
+    /// Returns the increments of the `Cognitive Complexity` metric
+    /// which occur directly in a space, in the order they are found
+    /// while traversing its nodes
+    ///
+    /// The increments are recorded only when they are requested
+    /// through [`MetricsOptions`], otherwise `None` is returned.
+    ///
+    /// [`MetricsOptions`]: ../struct.MetricsOptions.html
+    pub fn increments(&self) -> Option<&[Increment]> {
+        self.increments.as_deref()
+    }
+
+    pub(crate) fn record_increments(&mut self) {
+        self.increments = Some(Vec::new());
+    }
+
     pub(crate) fn finalize(&mut self, total_space_functions: usize) {
         self.total_space_functions = total_space_functions;
     }
+
+    pub(crate) fn add_call(&mut self, node: &Node) {
+        if self.increments.is_some() {
+            self.call_increments.push(Increment::new(node, 0, 1));
+        }
+    }
+
+    fn add_increment(&mut self, node: &Node, nesting: usize, increment: usize) {
+        self.structural += increment;
+        if let Some(increments) = &mut self.increments {
+            increments.push(Increment::new(node, nesting, increment));
+        }
+    }
+}
+
+// Marks the recursive functions contained in a space, adding their increments
+// also to the enclosing spaces when the cognitive complexity is computed.
+// Returns the added increments.
+fn mark_recursive(
+    space: &mut FuncSpace,
+    recursive_calls: &mut impl Iterator<Item = Option<usize>>,
+    cognitive: bool,
+) -> usize {
+    let mut added = 0;
+    if space.kind == SpaceKind::Function {
+        if let Some(call) = recursive_calls.next().flatten() {
+            space.recursive = true;
+            if cognitive {
+                let stats = &mut space.metrics.cognitive;
+                stats.structural += 1;
+                if let Some(increments) = &mut stats.increments {
+                    increments.extend(stats.call_increments.get(call).cloned());
+                }
+                added += 1;
+            }
+        }
+    }
+
+    let mut nested = 0;
+    for subspace in &mut space.spaces {
+        nested += mark_recursive(subspace, recursive_calls, cognitive);
+    }
+    space.metrics.cognitive.structural += nested;
+
+    added + nested
+}
+
+/// Detects the directly and the mutually recursive functions contained
+/// in a file, incrementing by one the `Cognitive Complexity` of each of them
+/// when it is computed.
+pub(crate) fn compute_recursion(space: &mut FuncSpace, cognitive: bool) {
+    let recursive_calls = {
+        let functions = &FileFunctions::new(space);
+        let callees = |i: usize| {
+            functions
+                .get(i)
+                .calls
+                .iter()
+                .flat_map(move |call| functions.callees(i, call))
+        };
+
+        // The functions reachable from each function through at least one call
+        let reachable: Vec<HashSet<usize>> = (0..functions.len())
+            .map(|i| {
+                let mut visited = HashSet::new();
+                let mut stack: Vec<usize> = callees(i).collect();
+                while let Some(j) = stack.pop() {
+                    if visited.insert(j) {
+                        stack.extend(callees(j));
+                    }
+                }
+                visited
+            })
+            .collect();
+
+        // The first call of each recursive function which closes a cycle
+        (0..functions.len())
+            .map(|i| {
+                if !reachable[i].contains(&i) {
+                    return None;
+                }
+                functions.get(i).calls.iter().position(|call| {
+                    functions
+                        .callees(i, call)
+                        .any(|j| j == i || reachable[j].contains(&i))
+                })
+            })
+            .collect::<Vec<Option<usize>>>()
+    };
+
+    mark_recursive(space, &mut recursive_calls.into_iter(), cognitive);
 }
 
 #[doc(hidden)]
@@ -96,9 +276,9 @@ macro_rules! compute_booleans {
         let mut cursor = $node.object().walk();
         for child in $node.object().children(&mut cursor) {
             if let $( $typs )|* = child.kind_id().into() {
-                $stats.structural = $stats
-                    .boolean_seq
-                    .eval_based_on_prev(child.kind_id(), $stats.structural);
+                if $stats.boolean_seq.eval_based_on_prev(child.kind_id()) {
+                    increment_by_one(&Node::new(child), $stats);
+                }
             }
         }
     };
@@ -126,7 +306,7 @@ macro_rules! nesting_levels {
         // Reset the boolean sequence
         $stats.boolean_seq.reset();
 
-        increment($stats);
+        increment($node, $stats);
     };
     ($node: ident, $stats: ident,
      [$lambdas: pat => $( $lambdas_stop: pat )|*],
@@ -145,7 +325,7 @@ macro_rules! nesting_levels {
         // Reset the boolean sequence
         $stats.boolean_seq.reset();
 
-        increment($stats);
+        increment($node, $stats);
     };
 }
 
@@ -164,34 +344,28 @@ impl BoolSequence {
         self.boolean_op = Some(not_id);
     }
 
-    fn eval_based_on_prev(&mut self, bool_id: u16, structural: usize) -> usize {
+    fn eval_based_on_prev(&mut self, bool_id: u16) -> bool {
         if let Some(prev) = self.boolean_op {
-            if prev != bool_id {
-                // The boolean operator is different from the previous one, so
-                // the counter is incremented.
-                structural + 1
-            } else {
-                // The boolean operator is equal to the previous one, so
-                // the counter is not incremented.
-                structural
-            }
+            // The counter is incremented only if the boolean operator
+            // is different from the previous one
+            prev != bool_id
         } else {
             // Save the first boolean operator in a sequence of
             // logical operators and increment the counter.
             self.boolean_op = Some(bool_id);
-            structural + 1
+            true
         }
     }
 }
 
 #[inline(always)]
-fn increment(stats: &mut Stats) {
-    stats.structural += stats.nesting + 1;
+fn increment(node: &Node, stats: &mut Stats) {
+    stats.add_increment(node, stats.nesting, stats.nesting + 1);
 }
 
 #[inline(always)]
-fn increment_by_one(stats: &mut Stats) {
-    stats.structural += 1;
+fn increment_by_one(node: &Node, stats: &mut Stats) {
+    stats.add_increment(node, 0, 1);
 }
 
 impl Cognitive for PythonCode {
@@ -210,17 +384,17 @@ impl Cognitive for PythonCode {
             ElifClause => {
                 // No nesting increment for them because their cost has already
                 // been paid by the if construct
-                increment_by_one(stats);
+                increment_by_one(node, stats);
                 // Reset the boolean sequence
                 stats.boolean_seq.reset();
             }
             ElseClause | FinallyClause => {
                 // No nesting increment for them because their cost has already
                 // been paid by the if construct
-                increment_by_one(stats);
+                increment_by_one(node, stats);
             }
             ExceptClause => {
-                increment(stats);
+                increment(node, stats);
             }
             ExpressionList | ExpressionStatement | Tuple => {
                 stats.boolean_seq.reset();
@@ -230,11 +404,14 @@ impl Cognitive for PythonCode {
             }
             BooleanOperator => {
                 if count_specific_ancestors!(node, BooleanOperator, Lambda) == 0 {
-                    stats.structural += count_specific_ancestors!(
+                    let lambdas = count_specific_ancestors!(
                         node,
                         Lambda,
                         ExpressionList | IfStatement | ForStatement | WhileStatement
                     );
+                    if lambdas > 0 {
+                        stats.add_increment(node, lambdas, lambdas);
+                    }
                 }
                 compute_booleans!(node, stats, And | Or);
             }
@@ -270,12 +447,12 @@ impl Cognitive for RustCode {
                 );
             }
             Else /*else-if also */ => {
-                increment_by_one(stats);
+                increment_by_one(node, stats);
             }
             BreakExpression | ContinueExpression => {
                 if let Some(label_child) = node.object().child(1) {
                     if let LoopLabel = label_child.kind_id().into() {
-                        increment_by_one(stats);
+                        increment_by_one(node, stats);
                     }
                 }
             }
@@ -324,7 +501,7 @@ impl Cognitive for CppCode {
                 );
             }
             GotoStatement | Else /* else-if also */ => {
-                increment_by_one(stats);
+                increment_by_one(node, stats);
             }
             UnaryExpression2 => {
                 stats.boolean_seq.not_operator(node.object().kind_id());
@@ -376,7 +553,7 @@ macro_rules! js_cognitive {
                     );
                 }
                 Else /* else-if also */ => {
-                    increment_by_one(stats);
+                    increment_by_one(node, stats);
                 }
                 ExpressionStatement => {
                     // Reset the boolean sequence
@@ -410,9 +587,189 @@ impl Cognitive for TsxCode {
     js_cognitive!(Tsx);
 }
 
+impl Cognitive for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Java::*;
+
+        match node.object().kind_id().into() {
+            IfStatement => {
+                if !Self::is_else_if(node) {
+                    nesting_levels!(
+                        node, stats,
+                        [LambdaExpression => Program],
+                        [IfStatement
+                            | ForStatement
+                            | EnhancedForStatement
+                            | WhileStatement
+                            | DoStatement
+                            | SwitchStatement
+                            | CatchClause
+                            | TernaryExpression => MethodDeclaration | ConstructorDeclaration]
+                    );
+                }
+            }
+            ForStatement | EnhancedForStatement | WhileStatement | DoStatement
+            | SwitchStatement | CatchClause | TernaryExpression => {
+                nesting_levels!(
+                    node, stats,
+                    [LambdaExpression => Program],
+                    [IfStatement
+                        | ForStatement
+                        | EnhancedForStatement
+                        | WhileStatement
+                        | DoStatement
+                        | SwitchStatement
+                        | CatchClause
+                        | TernaryExpression => MethodDeclaration | ConstructorDeclaration]
+                );
+            }
+            Else /* else-if also */ => {
+                increment_by_one(node, stats);
+            }
+            BreakStatement | ContinueStatement => {
+                if let Some(label_child) = node.object().child(1) {
+                    if let Identifier = label_child.kind_id().into() {
+                        increment_by_one(node, stats);
+                    }
+                }
+            }
+            ExpressionStatement => {
+                // Reset the boolean sequence
+                stats.boolean_seq.reset();
+            }
+            UnaryExpression => {
+                stats.boolean_seq.not_operator(node.object().kind_id());
+            }
+            BinaryExpression => {
+                compute_booleans!(node, stats, AMPAMP | PIPEPIPE);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Cognitive for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use crate::languages::Go::*;
+
+        match node.object().kind_id().into() {
+            IfStatement => {
+                if !Self::is_else_if(node) {
+                    nesting_levels!(
+                        node, stats,
+                        [FuncLiteral => SourceFile],
+                        [IfStatement
+                            | ForStatement
+                            | ExpressionSwitchStatement
+                            | TypeSwitchStatement
+                            | SelectStatement => FunctionDeclaration | MethodDeclaration]
+                    );
+                }
+            }
+            ForStatement | ExpressionSwitchStatement | TypeSwitchStatement | SelectStatement => {
+                nesting_levels!(
+                    node, stats,
+                    [FuncLiteral => SourceFile],
+                    [IfStatement
+                        | ForStatement
+                        | ExpressionSwitchStatement
+                        | TypeSwitchStatement
+                        | SelectStatement => FunctionDeclaration | MethodDeclaration]
+                );
+            }
+            Else /* else-if also */ | GotoStatement => {
+                increment_by_one(node, stats);
+            }
+            BreakStatement | ContinueStatement => {
+                if let Some(label_child) = node.object().child(1) {
+                    if let LabelName = label_child.kind_id().into() {
+                        increment_by_one(node, stats);
+                    }
+                }
+            }
+            AssignmentStatement | ShortVarDeclaration | ReturnStatement => {
+                // Reset the boolean sequence
+                stats.boolean_seq.reset();
+            }
+            UnaryExpression => {
+                stats.boolean_seq.not_operator(node.object().kind_id());
+            }
+            BinaryExpression => {
+                compute_booleans!(node, stats, AMPAMP | PIPEPIPE);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Cognitive for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.object().kind_id().into() {
+            IfStatement => {
+                if !Self::is_else_if(node) {
+                    nesting_levels!(
+                        node, stats,
+                        [LambdaExpression => CompilationUnit],
+                        [IfStatement
+                            | ForStatement
+                            | ForEachStatement
+                            | WhileStatement
+                            | DoStatement
+                            | SwitchStatement
+                            | SwitchExpression
+                            | CatchClause
+                            | ConditionalExpression => MethodDeclaration
+                                | ConstructorDeclaration
+                                | DestructorDeclaration
+                                | OperatorDeclaration
+                                | ConversionOperatorDeclaration
+                                | AccessorDeclaration]
+                    );
+                }
+            }
+            ForStatement | ForEachStatement | WhileStatement | DoStatement | SwitchStatement
+            | SwitchExpression | CatchClause | ConditionalExpression => {
+                nesting_levels!(
+                    node, stats,
+                    [LambdaExpression => CompilationUnit],
+                    [IfStatement
+                        | ForStatement
+                        | ForEachStatement
+                        | WhileStatement
+                        | DoStatement
+                        | SwitchStatement
+                        | SwitchExpression
+                        | CatchClause
+                        | ConditionalExpression => MethodDeclaration
+                            | ConstructorDeclaration
+                            | DestructorDeclaration
+                            | OperatorDeclaration
+                            | ConversionOperatorDeclaration
+                            | AccessorDeclaration]
+                );
+            }
+            Else /* else-if also */ | GotoStatement => {
+                increment_by_one(node, stats);
+            }
+            ExpressionStatement | LocalDeclarationStatement | ReturnStatement => {
+                // Reset the boolean sequence
+                stats.boolean_seq.reset();
+            }
+            PrefixUnaryExpression => {
+                stats.boolean_seq.not_operator(node.object().kind_id());
+            }
+            BinaryExpression => {
+                compute_booleans!(node, stats, AMPAMP | PIPEPIPE);
+            }
+            _ => {}
+        }
+    }
+}
+
 impl Cognitive for PreprocCode {}
 impl Cognitive for CcommentCode {}
-impl Cognitive for JavaCode {}
 
 #[cfg(test)]
 mod tests {
@@ -1235,4 +1592,678 @@ mod tests {
             [(cognitive_average, 9.0)]
         );
     }
+
+    #[test]
+    fn java_no_cognitive() {
+        check_metrics!(
+            "int a = 42;",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 0, usize)],
+            [(cognitive_average, f64::NAN)]
+        );
+    }
+
+    #[test]
+    fn java_simple_function() {
+        check_metrics!(
+            "class X {
+                 void f(boolean a, boolean b) {
+                     if (a && b) { // +2 (+1 &&)
+                         return;
+                     }
+                     if (c && d) { // +2 (+1 &&)
+                         return;
+                     }
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 4, usize)],
+            [(cognitive_average, 4.0)]
+        );
+    }
+
+    #[test]
+    fn java_sequence_different_booleans() {
+        check_metrics!(
+            "class X {
+                 void f(boolean a, boolean b, boolean c) {
+                     if (a && b || c) { // +3 (+1 &&, +1 ||)
+                         return;
+                     }
+                     if (a && !(b && c)) { // +3 (+1 &&, +1 &&)
+                         return;
+                     }
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 6, usize)],
+            [(cognitive_average, 6.0)]
+        );
+    }
+
+    #[test]
+    fn java_1_level_nesting() {
+        check_metrics!(
+            "class X {
+                 void f(boolean a, boolean b) {
+                     if (a) { // +1
+                         for (int i = 0; i < 10; i++) { // +2 (nesting = 1)
+                             while (b) { // +3 (nesting = 2)
+                                 b = false;
+                             }
+                         }
+                     } else if (b) { // +1
+                         return;
+                     } else { // +1
+                         return;
+                     }
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 8, usize)],
+            [(cognitive_average, 8.0)]
+        );
+    }
+
+    #[test]
+    fn java_break_continue() {
+        check_metrics!(
+            "class X {
+                 void f(int[][] m) {
+                     outer:
+                     for (int[] row : m) { // +1
+                         for (int v : row) { // +2 (nesting = 1)
+                             if (v == 0) { // +3 (nesting = 2)
+                                 break outer; // +1
+                             }
+                             if (v < 0) { // +3 (nesting = 2)
+                                 continue;
+                             }
+                         }
+                     }
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 10, usize)],
+            [(cognitive_average, 10.0)]
+        );
+    }
+
+    #[test]
+    fn java_switch_try() {
+        check_metrics!(
+            "class X {
+                 int f(int a) {
+                     try {
+                         switch (a) { // +1
+                             case 1:
+                                 return a > 0 ? 1 : 0; // +2 (nesting = 1)
+                             default:
+                                 return 0;
+                         }
+                     } catch (Exception e) { // +1
+                         return -1;
+                     }
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 4, usize)],
+            [(cognitive_average, 4.0)]
+        );
+    }
+
+    #[test]
+    fn java_lambda() {
+        check_metrics!(
+            "class X {
+                 void f(List<Integer> l) {
+                     l.forEach(x -> {
+                         if (x > 0) { // +2 (nesting = 1)
+                             System.out.println(x);
+                         }
+                     });
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 2, usize)],
+            [(cognitive_average, 1.0)] // 1 method + 1 lambda = 2
+        );
+    }
+
+    #[test]
+    fn go_no_cognitive() {
+        check_metrics!(
+            "var a = 42",
+            "foo.go",
+            GoParser,
+            cognitive,
+            [(cognitive, 0, usize)],
+            [(cognitive_average, f64::NAN)]
+        );
+    }
+
+    #[test]
+    fn go_simple_function() {
+        check_metrics!(
+            "func f(a, b bool) {
+                 if a && b { // +2 (+1 &&)
+                     return
+                 }
+                 if c && d { // +2 (+1 &&)
+                     return
+                 }
+             }",
+            "foo.go",
+            GoParser,
+            cognitive,
+            [(cognitive, 4, usize)],
+            [(cognitive_average, 4.0)]
+        );
+    }
+
+    #[test]
+    fn go_sequence_booleans() {
+        check_metrics!(
+            "func f(a, b, c bool) bool {
+                 x := a && b || c // +2
+                 y := a && b // +1
+                 return x && !y // +1
+             }",
+            "foo.go",
+            GoParser,
+            cognitive,
+            [(cognitive, 4, usize)],
+            [(cognitive_average, 4.0)]
+        );
+    }
+
+    #[test]
+    fn go_1_level_nesting() {
+        check_metrics!(
+            "func f(a, b bool) {
+                 if a { // +1
+                     for i := 0; i < 10; i++ { // +2 (nesting = 1)
+                         switch i { // +3 (nesting = 2)
+                         case 1:
+                             return
+                         }
+                     }
+                 } else if b { // +1
+                     return
+                 } else { // +1
+                     return
+                 }
+             }",
+            "foo.go",
+            GoParser,
+            cognitive,
+            [(cognitive, 8, usize)],
+            [(cognitive_average, 8.0)]
+        );
+    }
+
+    #[test]
+    fn go_labels_goto() {
+        check_metrics!(
+            "func f(m [][]int) {
+                 if len(m) == 0 { // +1
+                     goto done // +1
+                 }
+             outer:
+                 for _, row := range m { // +1
+                     for _, v := range row { // +2 (nesting = 1)
+                         if v == 0 { // +3 (nesting = 2)
+                             break outer // +1
+                         }
+                         if v < 0 { // +3 (nesting = 2)
+                             continue
+                         }
+                     }
+                 }
+             done:
+                 return
+             }",
+            "foo.go",
+            GoParser,
+            cognitive,
+            [(cognitive, 12, usize)],
+            [(cognitive_average, 12.0)]
+        );
+    }
+
+    #[test]
+    fn go_select() {
+        check_metrics!(
+            "func f(c chan int, quit chan bool) {
+                 for { // +1
+                     select { // +2 (nesting = 1)
+                     case x := <-c:
+                         fmt.Println(x)
+                     case <-quit:
+                         return
+                     }
+                 }
+             }",
+            "foo.go",
+            GoParser,
+            cognitive,
+            [(cognitive, 3, usize)],
+            [(cognitive_average, 3.0)]
+        );
+    }
+
+    #[test]
+    fn go_goroutine_closure() {
+        check_metrics!(
+            "func f(c chan int) {
+                 go func() {
+                     if x := <-c; x > 0 { // +2 (nesting = 1)
+                         fmt.Println(x)
+                     }
+                 }()
+             }",
+            "foo.go",
+            GoParser,
+            cognitive,
+            [(cognitive, 2, usize)],
+            [(cognitive_average, 1.0)] // 1 function + 1 closure = 2
+        );
+    }
+
+    #[test]
+    fn csharp_no_cognitive() {
+        check_metrics!(
+            "class X { int a = 42; }",
+            "foo.cs",
+            CsharpParser,
+            cognitive,
+            [(cognitive, 0, usize)],
+            [(cognitive_average, f64::NAN)]
+        );
+    }
+
+    #[test]
+    fn csharp_simple_function() {
+        check_metrics!(
+            "class X {
+                 void F(bool a, bool b) {
+                     if (a && b) { // +2 (+1 &&)
+                         return;
+                     }
+                     if (c && d) { // +2 (+1 &&)
+                         return;
+                     }
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cognitive,
+            [(cognitive, 4, usize)],
+            [(cognitive_average, 4.0)]
+        );
+    }
+
+    #[test]
+    fn csharp_sequence_booleans() {
+        check_metrics!(
+            "class X {
+                 bool F(bool a, bool b, bool c) {
+                     var x = a && b || c; // +2
+                     var y = a && !(b && c); // +2
+                     return x && y; // +1
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cognitive,
+            [(cognitive, 5, usize)],
+            [(cognitive_average, 5.0)]
+        );
+    }
+
+    #[test]
+    fn csharp_1_level_nesting() {
+        check_metrics!(
+            "class X {
+                 void F(bool a, bool b) {
+                     if (a) { // +1
+                         for (int i = 0; i < 10; i++) { // +2 (nesting = 1)
+                             foreach (var c in s) { // +3 (nesting = 2)
+                                 b = false;
+                             }
+                         }
+                     } else if (b) { // +1
+                         return;
+                     } else { // +1
+                         return;
+                     }
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cognitive,
+            [(cognitive, 8, usize)],
+            [(cognitive_average, 8.0)]
+        );
+    }
+
+    #[test]
+    fn csharp_switch_try() {
+        check_metrics!(
+            "class X {
+                 int F(int a) {
+                     try {
+                         switch (a) { // +1
+                             case 1:
+                                 return a > 0 ? 1 : 0; // +2 (nesting = 1)
+                             default:
+                                 return 0;
+                         }
+                     } catch (Exception e) { // +1
+                         return -1;
+                     }
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cognitive,
+            [(cognitive, 4, usize)],
+            [(cognitive_average, 4.0)]
+        );
+    }
+
+    #[test]
+    fn csharp_switch_expression_goto() {
+        check_metrics!(
+            "class X {
+                 string F(int a) {
+                     if (a > 0) { // +1
+                         return a switch { // +2 (nesting = 1)
+                             1 => \"one\",
+                             _ => \"other\"
+                         };
+                     }
+                     goto end; // +1
+                     end:
+                     return \"\";
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cognitive,
+            [(cognitive, 4, usize)],
+            [(cognitive_average, 4.0)]
+        );
+    }
+
+    #[test]
+    fn csharp_lambda() {
+        check_metrics!(
+            "class X {
+                 void F(List<int> l) {
+                     l.ForEach(x => {
+                         if (x > 0) { // +2 (nesting = 1)
+                             Console.WriteLine(x);
+                         }
+                     });
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cognitive,
+            [(cognitive, 2, usize)],
+            [(cognitive_average, 1.0)] // 1 method + 1 lambda = 2
+        );
+    }
+
+    #[test]
+    fn python_increments() {
+        let path = PathBuf::from("foo.py");
+        let source = "def f(a, b, c):
+    if a:  # +1
+        for i in b:  # +2 (nesting = 1)
+            pass
+    return a and b or c  # +2
+";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+        let options = MetricsOptions {
+            cognitive_increments: true,
+            ..Default::default()
+        };
+        let space = metrics_with_options(&parser, &path, &options).unwrap();
+        let func = &space.spaces[0];
+
+        assert_eq!(func.metrics.cognitive.cognitive() as usize, 5);
+        let increments: Vec<_> = func
+            .metrics
+            .cognitive
+            .increments()
+            .unwrap()
+            .iter()
+            .map(|inc| {
+                (
+                    inc.kind.as_str(),
+                    inc.start_line,
+                    inc.start_column,
+                    inc.nesting,
+                    inc.increment,
+                )
+            })
+            .collect();
+        assert_eq!(
+            increments,
+            vec![
+                ("if_statement", 2, 5, 0, 1),
+                ("for_statement", 3, 9, 1, 2),
+                ("or", 5, 20, 0, 1),
+                ("and", 5, 14, 0, 1),
+            ]
+        );
+
+        // The increments are not propagated to the parent space
+        assert!(space.metrics.cognitive.increments().unwrap().is_empty());
+    }
+
+    #[test]
+    fn java_no_increments() {
+        let path = PathBuf::from("foo.java");
+        let source = "class X { void f(boolean a) { if (a) {} } }\n";
+        let parser = JavaParser::new(source.as_bytes().to_vec(), &path, None);
+        let space = metrics(&parser, &path).unwrap();
+
+        assert_eq!(space.metrics.cognitive.cognitive() as usize, 1);
+        assert!(space.metrics.cognitive.increments().is_none());
+    }
+
+    #[test]
+    fn python_recursion() {
+        check_metrics!(
+            "def fact(n):
+                 if n <= 1:  # +1
+                     return 1
+                 return n * fact(n - 1)  # +1 (recursion)
+             def f(n):
+                 return fact(n)",
+            "foo.py",
+            PythonParser,
+            cognitive,
+            [(cognitive, 2, usize)],
+            [(cognitive_average, 1.0)]
+        );
+    }
+
+    #[test]
+    fn java_mutual_recursion() {
+        check_metrics!(
+            "class X {
+                 boolean isEven(int n) {
+                     if (n == 0) { // +1
+                         return true;
+                     }
+                     return isOdd(n - 1); // +1 (recursion)
+                 }
+                 boolean isOdd(int n) {
+                     return n != 0 && isEven(n - 1); // +1 (&&) +1 (recursion)
+                 }
+                 int g(int n) {
+                     return isOdd(n) ? 1 : 0; // +1
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 5, usize)]
+        );
+    }
+
+    #[test]
+    fn recursive_spaces() {
+        let path = PathBuf::from("foo.rs");
+        let source = "fn a(n: u32) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    self::b(n - 1)
+}
+fn b(n: u32) -> u32 {
+    a(n)
+}
+fn c(n: u32) -> u32 {
+    b(n) + c(n)
+}
+fn d(n: u32) -> u32 {
+    a(n)
+}
+";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        let options = MetricsOptions {
+            cognitive_increments: true,
+            ..Default::default()
+        };
+        let space = metrics_with_options(&parser, &path, &options).unwrap();
+
+        let recursive: Vec<_> = space
+            .spaces
+            .iter()
+            .map(|s| (s.name.as_deref().unwrap(), s.recursive))
+            .collect();
+        assert_eq!(
+            recursive,
+            vec![("a", true), ("b", true), ("c", true), ("d", false)]
+        );
+        assert!(!space.recursive);
+        assert_eq!(space.metrics.cognitive.cognitive() as usize, 4);
+
+        // The increment is located on the first call which closes the cycle
+        let increment = space.spaces[2].metrics.cognitive.increments().unwrap()[0].clone();
+        assert_eq!(
+            (
+                increment.start_line,
+                increment.start_column,
+                increment.increment
+            ),
+            (11, 12, 1)
+        );
+    }
+
+    #[test]
+    fn python_delegation_no_recursion() {
+        check_metrics!(
+            "class A(B):
+                 def __init__(self, a):
+                     super().__init__(a)
+                     self.a = a",
+            "foo.py",
+            PythonParser,
+            cognitive,
+            [(cognitive, 0, usize)]
+        );
+    }
+
+    #[test]
+    fn rust_delegation_no_recursion() {
+        check_metrics!(
+            "impl fmt::Display for A {
+                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                     self.inner.fmt(f)
+                 }
+             }",
+            "foo.rs",
+            RustParser,
+            cognitive,
+            [(cognitive, 0, usize)]
+        );
+    }
+
+    #[test]
+    fn java_delegation_no_recursion() {
+        check_metrics!(
+            "class A {
+                 B other;
+                 public boolean equals(Object o) {
+                     return other.equals(o);
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cognitive,
+            [(cognitive, 0, usize)]
+        );
+    }
+
+    #[test]
+    fn python_method_recursion() {
+        let path = PathBuf::from("foo.py");
+        let source = "class A:
+    def f(self, n):
+        return self.f(n - 1)
+    def g(self):
+        return self.h()
+class B:
+    def h(self):
+        return A().g()
+";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+        let space = metrics(&parser, &path).unwrap();
+
+        // A call on `self` is resolved only against the methods
+        // of the same class
+        let recursive: Vec<_> = space
+            .spaces
+            .iter()
+            .flat_map(|class| &class.spaces)
+            .map(|s| (s.name.as_deref().unwrap(), s.recursive))
+            .collect();
+        assert_eq!(recursive, vec![("f", true), ("g", false), ("h", false)]);
+        assert_eq!(space.metrics.cognitive.cognitive() as usize, 1);
+    }
+
+    #[test]
+    fn recursion_without_cognitive() {
+        let path = PathBuf::from("foo.py");
+        let source = "def f(n):\n    return f(n - 1)\n";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+        let options = MetricsOptions {
+            metrics: Some(vec![Metric::Loc]),
+            ..Default::default()
+        };
+        let space = metrics_with_options(&parser, &path, &options).unwrap();
+
+        assert!(space.spaces[0].recursive);
+        assert_eq!(space.spaces[0].metrics.cognitive.cognitive() as usize, 0);
+    }
 }
@@ -0,0 +1,338 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::node::Node;
+use crate::spaces::{FuncSpace, Metric};
+
+/// A metric defined outside of this crate.
+///
+/// A custom metric is computed as the built-in ones: each space has its own
+/// `Stats`, which are updated on the nodes of the space, excluding the nodes
+/// of its subspaces, and then finalized and merged into the `Stats` of the
+/// space containing it.
+///
+/// The finalized `Stats` of a space are serialized in its metrics,
+/// next to the built-in ones, using the name of the metric as key.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_code_analysis::{
+///     metrics_with_options, CustomMetric, FuncSpace, MetricsOptions, Node, ParserTrait,
+///     PythonParser,
+/// };
+///
+/// // Counts the string literals
+/// struct Strings;
+///
+/// impl CustomMetric for Strings {
+///     type Stats = usize;
+///
+///     fn name(&self) -> &str {
+///         "strings"
+///     }
+///
+///     fn compute(&self, node: &Node, _code: &[u8], _space: &FuncSpace, stats: &mut usize) {
+///         if node.kind() == "string" {
+///             *stats += 1;
+///         }
+///     }
+///
+///     fn merge(&self, stats: &mut usize, other: &usize) {
+///         *stats += other;
+///     }
+/// }
+///
+/// let source_code = "a = 'a'\ndef f():\n    return 'b' + 'c'\n";
+/// let path = PathBuf::from("foo.py");
+/// let parser = PythonParser::new(source_code.as_bytes().to_vec(), &path, None);
+///
+/// let mut options = MetricsOptions::default();
+/// options.custom.register(Strings);
+///
+/// let space = metrics_with_options(&parser, &path, &options).unwrap();
+/// assert_eq!(space.metrics.custom["strings"], 3);
+/// assert_eq!(space.spaces[0].metrics.custom["strings"], 2);
+/// ```
+pub trait CustomMetric: Send + Sync + 'static {
+    /// The data of the metric for a space
+    type Stats: Default + Serialize + 'static;
+
+    /// Returns the name of the metric, which must be different
+    /// from the names of the built-in metrics and of the other
+    /// registered custom metrics
+    fn name(&self) -> &str;
+
+    /// Updates the `Stats` of a space with one of its nodes
+    fn compute(&self, node: &Node, code: &[u8], space: &FuncSpace, stats: &mut Self::Stats);
+
+    /// Merges the `Stats` of a subspace into the `Stats`
+    /// of the space containing it
+    fn merge(&self, stats: &mut Self::Stats, other: &Self::Stats);
+
+    /// Completes the `Stats` of a space once all its nodes have been visited
+    /// and its subspaces merged, e.g. to compute an average
+    fn finalize(&self, _space: &FuncSpace, _stats: &mut Self::Stats) {}
+}
+
+// A custom metric whose `Stats` type has been erased,
+// so that several metrics can be registered together
+trait DynMetric: Send + Sync {
+    fn name(&self) -> &str;
+    fn new_stats(&self) -> Box<dyn Any>;
+    fn compute(&self, node: &Node, code: &[u8], space: &FuncSpace, stats: &mut dyn Any);
+    fn merge(&self, stats: &mut dyn Any, other: &dyn Any);
+    fn finalize(&self, space: &FuncSpace, stats: &mut dyn Any) -> Value;
+}
+
+impl<M: CustomMetric> DynMetric for M {
+    fn name(&self) -> &str {
+        CustomMetric::name(self)
+    }
+
+    fn new_stats(&self) -> Box<dyn Any> {
+        Box::new(M::Stats::default())
+    }
+
+    fn compute(&self, node: &Node, code: &[u8], space: &FuncSpace, stats: &mut dyn Any) {
+        if let Some(stats) = stats.downcast_mut() {
+            CustomMetric::compute(self, node, code, space, stats);
+        }
+    }
+
+    fn merge(&self, stats: &mut dyn Any, other: &dyn Any) {
+        if let (Some(stats), Some(other)) = (stats.downcast_mut(), other.downcast_ref()) {
+            CustomMetric::merge(self, stats, other);
+        }
+    }
+
+    fn finalize(&self, space: &FuncSpace, stats: &mut dyn Any) -> Value {
+        match stats.downcast_mut() {
+            Some(stats) => {
+                CustomMetric::finalize(self, space, stats);
+                serde_json::to_value(&*stats).unwrap_or_default()
+            }
+            None => Value::Null,
+        }
+    }
+}
+
+/// The custom metrics computed along with the built-in ones.
+#[derive(Clone, Default)]
+pub struct CustomMetrics(Vec<Arc<dyn DynMetric>>);
+
+impl fmt::Debug for CustomMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|metric| metric.name()))
+            .finish()
+    }
+}
+
+impl CustomMetrics {
+    /// Registers a custom metric.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name of the metric is the one of a built-in metric
+    /// or of a custom metric already registered, since the metrics of a space
+    /// are serialized using their names as keys.
+    pub fn register<M: CustomMetric>(&mut self, metric: M) {
+        let name = CustomMetric::name(&metric);
+        assert!(
+            Metric::all()
+                .iter()
+                .all(|metric| metric.to_string() != name),
+            "The custom metric {:?} has the name of a built-in metric",
+            name
+        );
+        assert!(
+            self.0.iter().all(|metric| metric.name() != name),
+            "The custom metric {:?} has already been registered",
+            name
+        );
+        self.0.push(Arc::new(metric));
+    }
+
+    /// Checks if no custom metric has been registered.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn new_stats(&self) -> Stats {
+        Stats(self.0.iter().map(|metric| metric.new_stats()).collect())
+    }
+
+    pub(crate) fn compute(&self, node: &Node, code: &[u8], space: &FuncSpace, stats: &mut Stats) {
+        for (metric, stats) in self.0.iter().zip(stats.0.iter_mut()) {
+            metric.compute(node, code, space, stats.as_mut());
+        }
+    }
+
+    pub(crate) fn merge(&self, stats: &mut Stats, other: &Stats) {
+        for (metric, (stats, other)) in self.0.iter().zip(stats.0.iter_mut().zip(&other.0)) {
+            metric.merge(stats.as_mut(), other.as_ref());
+        }
+    }
+
+    /// Finalizes the `Stats` of a space and stores them in its metrics
+    pub(crate) fn finalize(&self, space: &mut FuncSpace, stats: &mut Stats) {
+        for (metric, stats) in self.0.iter().zip(stats.0.iter_mut()) {
+            let value = metric.finalize(space, stats.as_mut());
+            space
+                .metrics
+                .custom
+                .insert(metric.name().to_string(), value);
+        }
+    }
+}
+
+/// The `Stats` of the custom metrics for a space.
+#[derive(Debug, Default)]
+pub(crate) struct Stats(Vec<Box<dyn Any>>);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use super::*;
+
+    // Computes the number and the average length of the identifiers
+    struct Identifiers;
+
+    #[derive(Default, Serialize)]
+    struct IdentifiersStats {
+        count: usize,
+        length: usize,
+        average: f64,
+    }
+
+    impl CustomMetric for Identifiers {
+        type Stats = IdentifiersStats;
+
+        fn name(&self) -> &str {
+            "identifiers"
+        }
+
+        fn compute(&self, node: &Node, code: &[u8], _space: &FuncSpace, stats: &mut Self::Stats) {
+            if node.kind() == "identifier" {
+                stats.count += 1;
+                stats.length += node.utf8_text(code).map_or(0, str::len);
+            }
+        }
+
+        fn merge(&self, stats: &mut Self::Stats, other: &Self::Stats) {
+            stats.count += other.count;
+            stats.length += other.length;
+        }
+
+        fn finalize(&self, _space: &FuncSpace, stats: &mut Self::Stats) {
+            stats.average = stats.length as f64 / stats.count as f64;
+        }
+    }
+
+    // Records the kind of a space
+    struct Kinds;
+
+    impl CustomMetric for Kinds {
+        type Stats = String;
+
+        fn name(&self) -> &str {
+            "kinds"
+        }
+
+        fn compute(&self, _node: &Node, _code: &[u8], space: &FuncSpace, stats: &mut String) {
+            if stats.is_empty() {
+                *stats = space.kind.to_string();
+            }
+        }
+
+        fn merge(&self, _stats: &mut String, _other: &String) {}
+    }
+
+    #[test]
+    fn custom_metrics() {
+        let mut options = MetricsOptions::default();
+        options.custom.register(Identifiers);
+        options.custom.register(Kinds);
+
+        let space = python_space(
+            "abcd = 1
+def foo(ab):
+    def bar():
+        return ab
+    return bar
+",
+            &options,
+        );
+        let foo = &space.spaces[0];
+        let bar = &foo.spaces[0];
+
+        // The name of a function belongs to its space
+        let identifiers = |space: &FuncSpace| {
+            let stats = &space.metrics.custom["identifiers"];
+            (stats["count"].clone(), stats["length"].clone())
+        };
+        assert_eq!(identifiers(bar), (2.into(), 5.into()));
+        assert_eq!(identifiers(foo), (5.into(), 13.into()));
+        assert_eq!(identifiers(&space), (6.into(), 17.into()));
+        assert_eq!(bar.metrics.custom["identifiers"]["average"], 2.5);
+        assert_eq!(foo.metrics.custom["identifiers"]["average"], 2.6);
+
+        assert_eq!(bar.metrics.custom["kinds"], "function");
+        assert_eq!(space.metrics.custom["kinds"], "unit");
+
+        // The custom metrics are serialized next to the built-in ones
+        let metrics = serde_json::to_value(&space.metrics).unwrap();
+        assert_eq!(metrics["identifiers"]["count"], 6);
+        assert_eq!(metrics["kinds"], "unit");
+        assert!(metrics["cyclomatic"].is_object());
+
+        let deserialized: CodeMetrics = serde_json::from_value(metrics).unwrap();
+        assert_eq!(deserialized.custom, space.metrics.custom);
+    }
+
+    // Has the name of a built-in metric
+    struct Loc;
+
+    impl CustomMetric for Loc {
+        type Stats = usize;
+
+        fn name(&self) -> &str {
+            "loc"
+        }
+
+        fn compute(&self, _node: &Node, _code: &[u8], _space: &FuncSpace, _stats: &mut usize) {}
+
+        fn merge(&self, _stats: &mut usize, _other: &usize) {}
+    }
+
+    #[test]
+    #[should_panic(expected = "has the name of a built-in metric")]
+    fn custom_metric_builtin_name() {
+        MetricsOptions::default().custom.register(Loc);
+    }
+
+    #[test]
+    #[should_panic(expected = "has already been registered")]
+    fn custom_metric_registered_twice() {
+        let mut options = MetricsOptions::default();
+        options.custom.register(Kinds);
+        options.custom.register(Kinds);
+    }
+
+    #[test]
+    fn no_custom_metrics() {
+        let space = python_space("def foo():\n    pass\n", &MetricsOptions::default());
+        assert!(space.metrics.custom.is_empty());
+
+        let metrics = serde_json::to_value(&space.metrics).unwrap();
+        let deserialized: CodeMetrics = serde_json::from_value(metrics).unwrap();
+        assert!(deserialized.custom.is_empty());
+    }
+}
@@ -1,7 +1,10 @@
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
 
+use super::average_count;
+
 use crate::checker::Checker;
 use crate::*;
 
@@ -33,6 +36,25 @@ impl Serialize for Stats {
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            sum: f64,
+            average: Option<f64>,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            cyclomatic: values.sum,
+            n: average_count(values.sum, values.average).unwrap_or(1),
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -169,9 +191,48 @@ impl Cyclomatic for CppCode {
     }
 }
 
+impl Cyclomatic for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Java::*;
+
+        match node.object().kind_id().into() {
+            If | For | While | Case | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+                stats.cyclomatic += 1.;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Cyclomatic for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use crate::languages::Go::*;
+
+        match node.object().kind_id().into() {
+            If | For | ExpressionCase | TypeCase | CommunicationCase | AMPAMP | PIPEPIPE => {
+                stats.cyclomatic += 1.;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Cyclomatic for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.object().kind_id().into() {
+            If | For | Foreach | While | Case | Catch | ConditionalExpression
+            | SwitchExpressionArm | AMPAMP | PIPEPIPE | QMARKQMARK => {
+                stats.cyclomatic += 1.;
+            }
+            _ => {}
+        }
+    }
+}
+
 impl Cyclomatic for PreprocCode {}
 impl Cyclomatic for CcommentCode {}
-impl Cyclomatic for JavaCode {}
 
 #[cfg(test)]
 mod tests {
@@ -288,4 +349,218 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn java_simple_class() {
+        check_metrics!(
+            "class X { // +2 (+1 unit space)
+                 void f(boolean a, boolean b) { // +1
+                     if (a && b) { // +2 (+1 &&)
+                         return;
+                     }
+                     for (int i = 0; i < 10; i++) { // +1
+                         while (b) { // +1
+                             b = false;
+                         }
+                     }
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cyclomatic,
+            [(cyclomatic, 7, usize)],
+            [
+                (cyclomatic_average, 7.0 / 3.0) // nspace = 3 (method, class and unit)
+            ]
+        );
+    }
+
+    #[test]
+    fn java_switch() {
+        check_metrics!(
+            "class X { // +2 (+1 unit space)
+                 int f(int a) { // +1
+                     switch (a) {
+                         case 1: // +1
+                             return 1;
+                         case 2: // +1
+                             return 2;
+                         default:
+                             return a > 0 ? a : -a; // +1
+                     }
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cyclomatic,
+            [(cyclomatic, 6, usize)],
+            [
+                (cyclomatic_average, 2.0) // nspace = 3 (method, class and unit)
+            ]
+        );
+    }
+
+    #[test]
+    fn java_interface_enum() {
+        check_metrics!(
+            "interface I { // +2 (+1 unit space)
+                 default int f(int a) { // +1
+                     return a > 0 ? 1 : 0; // +1
+                 }
+             }
+             enum E { // +1
+                 A, B;
+                 int g() { // +1
+                     return 1;
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            cyclomatic,
+            [(cyclomatic, 6, usize)],
+            [
+                (cyclomatic_average, 1.2) // nspace = 5 (2 methods, interface, enum and unit)
+            ]
+        );
+    }
+
+    #[test]
+    fn go_switch_select() {
+        check_metrics!(
+            "func f(a int) int { // +2 (+1 unit space)
+                 switch a {
+                 case 1, 2: // +1
+                     return 1
+                 default:
+                     return 0
+                 }
+             }
+             func g(c chan int, d chan int, ok bool) { // +1
+                 select {
+                 case x := <-c: // +1
+                     fmt.Println(x)
+                 case d <- 1: // +1
+                 default:
+                 }
+                 if x := len(c); x > 0 && ok { // +2 (+1 &&)
+                     fmt.Println(x)
+                 }
+             }",
+            "foo.go",
+            GoParser,
+            cyclomatic,
+            [(cyclomatic, 8, usize)],
+            [
+                (cyclomatic_average, 8.0 / 3.0) // nspace = 3 (2 functions and unit)
+            ]
+        );
+    }
+
+    #[test]
+    fn go_type_switch() {
+        check_metrics!(
+            "func f(v interface{}) string { // +2 (+1 unit space)
+                 switch v.(type) {
+                 case int: // +1
+                     return \"int\"
+                 case string: // +1
+                     return \"string\"
+                 }
+                 for i := 0; i < 3; i++ { // +1
+                     fmt.Println(i)
+                 }
+                 return \"\"
+             }",
+            "foo.go",
+            GoParser,
+            cyclomatic,
+            [(cyclomatic, 5, usize)],
+            [
+                (cyclomatic_average, 2.5) // nspace = 2 (function and unit)
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_simple_class() {
+        check_metrics!(
+            "class X { // +2 (+1 unit space)
+                 int F(int a, bool b) { // +1
+                     if (a > 0 && b) { // +2 (+1 &&)
+                         return 1;
+                     }
+                     foreach (var i in items) { // +1
+                         while (b) { // +1
+                             b = false;
+                         }
+                     }
+                     return a ?? 0; // +1
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cyclomatic,
+            [(cyclomatic, 8, usize)],
+            [
+                (cyclomatic_average, 8.0 / 3.0) // nspace = 3 (method, class and unit)
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_switch() {
+        check_metrics!(
+            "class X { // +2 (+1 unit space)
+                 int F(int a) { // +1
+                     switch (a) {
+                         case 1: // +1
+                             return 1;
+                         default:
+                             return a > 0 ? a : -a; // +1
+                     }
+                 }
+                 string G(int a) => a switch { // +1
+                     1 => \"one\", // +1
+                     _ => \"other\" // +1
+                 };
+             }",
+            "foo.cs",
+            CsharpParser,
+            cyclomatic,
+            [(cyclomatic, 8, usize)],
+            [
+                (cyclomatic_average, 2.0) // nspace = 4 (2 methods, class and unit)
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_spaces() {
+        check_metrics!(
+            "namespace N { // +2 (+1 unit space)
+                 interface I { // +1
+                     int F(); // +1
+                 }
+                 struct S { // +1
+                     int p;
+                     int P {
+                         get { return p; } // +1
+                         set { if (value > 0) { p = value; } } // +2
+                     }
+                 }
+                 record R(int A) { // +1
+                     int G() => A > 0 ? A : 0; // +2
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            cyclomatic,
+            [(cyclomatic, 11, usize)],
+            [
+                // nspace = 9 (2 methods, 2 accessors, interface, struct,
+                // record, namespace and unit)
+                (cyclomatic_average, 11.0 / 9.0)
+            ]
+        );
+    }
 }
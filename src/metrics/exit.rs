@@ -1,7 +1,10 @@
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
 
+use super::average_count;
+
 use crate::checker::Checker;
 use crate::*;
 
@@ -36,6 +39,25 @@ impl Serialize for Stats {
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            sum: f64,
+            average: Option<f64>,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            exit: values.sum as usize,
+            total_space_functions: average_count(values.sum, values.average).unwrap_or(1),
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "sum: {}, average: {}", self.exit(), self.exit_average())
@@ -135,9 +157,34 @@ impl Exit for CppCode {
     }
 }
 
+impl Exit for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if let Java::ReturnStatement = node.object().kind_id().into() {
+            stats.exit += 1;
+        }
+    }
+}
+
+impl Exit for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        // A return statement is a single exit point, even when it
+        // returns multiple values
+        if let Go::ReturnStatement = node.object().kind_id().into() {
+            stats.exit += 1;
+        }
+    }
+}
+
+impl Exit for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if let Csharp::ReturnStatement = node.object().kind_id().into() {
+            stats.exit += 1;
+        }
+    }
+}
+
 impl Exit for PreprocCode {}
 impl Exit for CcommentCode {}
-impl Exit for JavaCode {}
 
 #[cfg(test)]
 mod tests {
@@ -240,4 +287,80 @@ mod tests {
             [(exit_average, 0.5)] // 2 functions + 2 lambdas = 4
         );
     }
+
+    #[test]
+    fn java_no_exit() {
+        check_metrics!(
+            "int a = 42;",
+            "foo.java",
+            JavaParser,
+            nexits,
+            [(exit, 0, usize)],
+            [(exit_average, f64::NAN)] // 0 functions
+        );
+    }
+
+    #[test]
+    fn java_more_functions() {
+        check_metrics!(
+            "class X {
+                 int f(int a) {
+                     if (a > 0) {
+                         return a;
+                     }
+                     return -a;
+                 }
+                 void g() {
+                     return;
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            nexits,
+            [(exit, 3, usize)],
+            [(exit_average, 1.5)] // 2 functions
+        );
+    }
+
+    #[test]
+    fn go_multiple_return_values() {
+        check_metrics!(
+            "func f(a int) (int, error) {
+                 if a < 0 {
+                     return 0, errors.New(\"negative\")
+                 }
+                 return a, nil
+             }
+             func g() {
+                 defer func() {
+                     recover()
+                 }()
+             }",
+            "foo.go",
+            GoParser,
+            nexits,
+            [(exit, 2, usize)],
+            [(exit_average, 2.0 / 3.0)] // 2 functions + 1 closure = 3
+        );
+    }
+
+    #[test]
+    fn csharp_more_functions() {
+        check_metrics!(
+            "class X {
+                 int F(int a) {
+                     if (a > 0) {
+                         return a;
+                     }
+                     return -a;
+                 }
+                 int P { get { return 1; } }
+             }",
+            "foo.cs",
+            CsharpParser,
+            nexits,
+            [(exit, 3, usize)],
+            [(exit_average, 1.5)] // 1 method + 1 accessor = 2
+        );
+    }
 }
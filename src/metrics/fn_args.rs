@@ -1,7 +1,10 @@
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
 
+use super::average_count;
+
 use crate::checker::Checker;
 use crate::*;
 
@@ -9,17 +12,24 @@ use crate::*;
 ///
 /// This metric counts the number of arguments
 /// of a function/method.
+///
+/// The arguments of closures are counted separately from
+/// the arguments of functions.
 #[derive(Debug, Clone)]
 pub struct Stats {
-    nargs: usize,
-    total_space_functions: usize,
+    fn_nargs: usize,
+    closure_nargs: usize,
+    total_functions: usize,
+    total_closures: usize,
 }
 
 impl Default for Stats {
     fn default() -> Self {
         Self {
-            nargs: 0,
-            total_space_functions: 1,
+            fn_nargs: 0,
+            closure_nargs: 0,
+            total_functions: 1,
+            total_closures: 0,
         }
     }
 }
@@ -29,20 +39,69 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("nargs", 2)?;
+        let mut st = serializer.serialize_struct("nargs", 6)?;
         st.serialize_field("sum", &self.nargs())?;
         st.serialize_field("average", &self.nargs_average())?;
+        st.serialize_field("functions", &self.fn_args())?;
+        st.serialize_field("closures", &self.closure_args())?;
+        st.serialize_field("functions_average", &self.fn_args_average())?;
+        st.serialize_field("closures_average", &self.closure_args_average())?;
         st.end()
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            sum: f64,
+            average: Option<f64>,
+            functions: f64,
+            closures: f64,
+            functions_average: Option<f64>,
+            closures_average: Option<f64>,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        let total = average_count(values.sum, values.average);
+        let functions = average_count(values.functions, values.functions_average);
+        let closures = average_count(values.closures, values.closures_average);
+        // The number of functions or closures without arguments
+        // can only be obtained from the total one
+        let (total_functions, total_closures) = match (functions, closures, total) {
+            (Some(f), Some(c), _) => (f, c),
+            (Some(f), None, t) => (f, t.map_or(0, |t| t.saturating_sub(f))),
+            (None, Some(c), t) => (t.map_or(0, |t| t.saturating_sub(c)), c),
+            (None, None, t) => (t.unwrap_or(1), 0),
+        };
+        Ok(Self {
+            fn_nargs: values.functions as usize,
+            closure_nargs: values.closures as usize,
+            total_functions,
+            total_closures,
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "sum: {}, average: {}",
+            "sum: {}, \
+             average: {}, \
+             functions: {}, \
+             closures: {}, \
+             functions_average: {}, \
+             closures_average: {}",
             self.nargs(),
-            self.nargs_average()
+            self.nargs_average(),
+            self.fn_args(),
+            self.closure_args(),
+            self.fn_args_average(),
+            self.closure_args_average()
         )
     }
 }
@@ -50,12 +109,25 @@ impl fmt::Display for Stats {
 impl Stats {
     /// Merges a second `NArgs` metric into the first one
     pub fn merge(&mut self, other: &Stats) {
-        self.nargs += other.nargs;
+        self.fn_nargs += other.fn_nargs;
+        self.closure_nargs += other.closure_nargs;
     }
 
     /// Returns the `NArgs` metric value
+    ///
+    /// This value is the sum of the arguments of functions and closures.
     pub fn nargs(&self) -> f64 {
-        self.nargs as f64
+        self.fn_args() + self.closure_args()
+    }
+
+    /// Returns the number of function arguments in a space
+    pub fn fn_args(&self) -> f64 {
+        self.fn_nargs as f64
+    }
+
+    /// Returns the number of closure arguments in a space
+    pub fn closure_args(&self) -> f64 {
+        self.closure_nargs as f64
     }
 
     /// Returns the `NArgs` metric average value
@@ -63,11 +135,48 @@ impl Stats {
     /// This value is computed dividing the `NArgs` value
     /// for the total number of functions/closures in a space.
     pub fn nargs_average(&self) -> f64 {
-        self.nargs() / self.total_space_functions as f64
+        self.nargs() / (self.total_functions + self.total_closures) as f64
+    }
+
+    /// Returns the average number of function arguments in a space
+    ///
+    /// As for the `NArgs` average, the value is `NaN`
+    /// when a space contains no functions.
+    pub fn fn_args_average(&self) -> f64 {
+        self.fn_args() / self.total_functions as f64
     }
 
-    pub(crate) fn finalize(&mut self, total_space_functions: usize) {
-        self.total_space_functions = total_space_functions;
+    /// Returns the average number of closure arguments in a space
+    ///
+    /// As for the `NArgs` average, the value is `NaN`
+    /// when a space contains no closures.
+    pub fn closure_args_average(&self) -> f64 {
+        self.closure_args() / self.total_closures as f64
+    }
+
+    pub(crate) fn finalize(&mut self, total_functions: usize, total_closures: usize) {
+        self.total_functions = total_functions;
+        self.total_closures = total_closures;
+    }
+}
+
+#[inline(always)]
+fn count_args<T: Checker + ?Sized>(params: &Node) -> usize {
+    let mut nargs = 0;
+    params.act_on_child(&mut |n| {
+        if !T::is_non_arg(n) {
+            nargs += 1;
+        }
+    });
+    nargs
+}
+
+#[inline(always)]
+fn add_args<T: Checker + ?Sized>(node: &Node, stats: &mut Stats, nargs: usize) {
+    if T::is_closure(node) {
+        stats.closure_nargs += nargs;
+    } else {
+        stats.fn_nargs += nargs;
     }
 }
 
@@ -77,49 +186,185 @@ where
     Self: Checker,
 {
     fn compute(node: &Node, stats: &mut Stats) {
-        if !Self::is_func(node) {
+        if !Self::is_func(node) && !Self::is_closure(node) {
             return;
         }
 
         if let Some(params) = node.object().child_by_field_name("parameters") {
-            let node_params = Node::new(params);
-            node_params.act_on_child(&mut |n| {
-                if !Self::is_non_arg(n) {
-                    stats.nargs += 1;
-                }
-            });
+            let nargs = count_args::<Self>(&Node::new(params));
+            add_args::<Self>(node, stats, nargs);
         }
     }
 }
 
 impl NArgs for CppCode {
     fn compute(node: &Node, stats: &mut Stats) {
-        if !Self::is_func(node) {
+        if !Self::is_func(node) && !Self::is_closure(node) {
             return;
         }
 
+        // The parameters of a lambda are in its abstract declarator
         if let Some(declarator) = node.object().child_by_field_name("declarator") {
             if let Some(params) = declarator.child_by_field_name("parameters") {
-                let node_params = Node::new(params);
-                node_params.act_on_child(&mut |n| {
-                    if !Self::is_non_arg(n) {
-                        stats.nargs += 1;
-                    }
-                });
+                let nargs = count_args::<Self>(&Node::new(params));
+                add_args::<Self>(node, stats, nargs);
             }
         }
     }
 }
 
-impl NArgs for PythonCode {}
-impl NArgs for MozjsCode {}
-impl NArgs for JavascriptCode {}
-impl NArgs for TypescriptCode {}
-impl NArgs for TsxCode {}
+impl NArgs for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if !Self::is_func(node) && !Self::is_closure(node) {
+            return;
+        }
+
+        if let Some(params) = node.object().child_by_field_name("parameters") {
+            let mut nargs = 0;
+            Node::new(params).act_on_child(&mut |n| {
+                match n.object().kind_id().into() {
+                    // A bare * only separates the keyword-only arguments
+                    Python::ListSplatPattern if n.object().child_count() == 0 => {}
+                    // *args, **kwargs and default arguments are one argument each
+                    _ if !Self::is_non_arg(n) => {
+                        nargs += 1;
+                    }
+                    _ => {}
+                }
+            });
+            add_args::<Self>(node, stats, nargs);
+        }
+    }
+}
+
+#[inline(always)]
+fn compute_js_args<T: Checker>(node: &Node, stats: &mut Stats) {
+    if !T::is_func(node) && !T::is_closure(node) {
+        return;
+    }
+
+    // An arrow function with a single parameter and no parentheses: x => x
+    if node.object().child_by_field_name("parameter").is_some() {
+        add_args::<T>(node, stats, 1);
+    } else if let Some(params) = node.object().child_by_field_name("parameters") {
+        // Destructured, rest and optional parameters are one argument each
+        let nargs = count_args::<T>(&Node::new(params));
+        add_args::<T>(node, stats, nargs);
+    }
+}
+
+impl NArgs for MozjsCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_js_args::<Self>(node, stats);
+    }
+}
+
+impl NArgs for JavascriptCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_js_args::<Self>(node, stats);
+    }
+}
+
+impl NArgs for TypescriptCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_js_args::<Self>(node, stats);
+    }
+}
+
+impl NArgs for TsxCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_js_args::<Self>(node, stats);
+    }
+}
+
 impl NArgs for RustCode {}
 impl NArgs for PreprocCode {}
 impl NArgs for CcommentCode {}
-impl NArgs for JavaCode {}
+
+impl NArgs for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if !Self::is_func(node) {
+            return;
+        }
+
+        if let Some(params) = node.object().child_by_field_name("parameters") {
+            let nargs = match params.kind_id().into() {
+                // A lambda with a single parameter and no parentheses: x -> x
+                Java::Identifier => 1,
+                _ => count_args::<Self>(&Node::new(params)),
+            };
+            add_args::<Self>(node, stats, nargs);
+        }
+    }
+}
+
+impl NArgs for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if !Self::is_func(node) {
+            return;
+        }
+
+        if let Some(params) = node.object().child_by_field_name("parameters") {
+            let mut nargs = 0;
+            Node::new(params).act_on_child(&mut |n| match n.object().kind_id().into() {
+                // Several names can share the same type: func f(a, b int)
+                Go::ParameterDeclaration => {
+                    let mut cursor = n.object().walk();
+                    let names = n
+                        .object()
+                        .children(&mut cursor)
+                        .filter(|c| c.kind_id() == Go::Identifier)
+                        .count();
+                    // An unnamed parameter has only a type: func(int)
+                    nargs += names.max(1);
+                }
+                Go::VariadicParameterDeclaration => {
+                    nargs += 1;
+                }
+                _ => {}
+            });
+            add_args::<Self>(node, stats, nargs);
+        }
+    }
+}
+
+impl NArgs for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if !Self::is_func(node) {
+            return;
+        }
+
+        // The parameters of a lambda or of an anonymous method have no field name
+        let params = node.object().child_by_field_name("parameters").or_else(|| {
+            (0..node.object().child_count())
+                .filter_map(|i| node.object().child(i))
+                .find(|child| {
+                    matches!(
+                        child.kind_id().into(),
+                        Csharp::ParameterList | Csharp::Identifier
+                    )
+                })
+        });
+        if let Some(params) = params {
+            let mut nargs = 0;
+            match params.kind_id().into() {
+                // A lambda with a single parameter and no parentheses: x => x
+                Csharp::Identifier => {
+                    nargs += 1;
+                }
+                _ => {
+                    Node::new(params).act_on_child(&mut |n| {
+                        if let Csharp::Parameter | Csharp::ParameterArray = n.object().kind_id().into()
+                        {
+                            nargs += 1;
+                        }
+                    });
+                }
+            }
+            add_args::<Self>(node, stats, nargs);
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -184,9 +429,273 @@ mod tests {
             "foo.py",
             PythonParser,
             nargs,
-            // FIXME: Consider lambda arguments also
-            [(nargs, 3, usize)],
-            [(nargs_average, 0.75)] // 2 functions + 2 lambdas = 4
+            [
+                (nargs, 5, usize),
+                (fn_args, 3, usize),
+                (closure_args, 2, usize)
+            ],
+            [
+                (nargs_average, 1.25), // 2 functions + 2 lambdas = 4
+                (fn_args_average, 1.5),
+                (closure_args_average, 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn python_args_kwargs_defaults_nargs() {
+        check_metrics!(
+            "def f(a, b=1, *args, c, **kwargs):
+                 pass
+             def g(a, *, b):
+                 pass",
+            "foo.py",
+            PythonParser,
+            nargs,
+            // The bare * is not an argument
+            [(nargs, 7, usize), (fn_args, 7, usize)],
+            [
+                (nargs_average, 3.5), // 2 functions
+                (fn_args_average, 3.5),
+                (closure_args_average, f64::NAN) // 0 closures
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_self_receivers_nargs() {
+        check_metrics!(
+            "impl A {
+                 fn new(a: i32, b: i32) -> Self { A }
+                 fn get(&self) -> i32 { 0 }
+                 fn set(&mut self, v: i32) {}
+                 fn into(self) {}
+             }",
+            "foo.rs",
+            RustParser,
+            nargs,
+            // A self receiver is an argument
+            [(nargs, 6, usize), (fn_args, 6, usize)],
+            [(nargs_average, 1.5)] // 4 functions
+        );
+    }
+
+    #[test]
+    fn rust_closures_nargs() {
+        check_metrics!(
+            "fn f(a: i32) {
+                 let g = |x, y: i32| x + y;
+                 let h = || 1;
+             }",
+            "foo.rs",
+            RustParser,
+            nargs,
+            [
+                (nargs, 3, usize),
+                (fn_args, 1, usize),
+                (closure_args, 2, usize)
+            ],
+            [
+                (nargs_average, 1.0), // 1 function + 2 closures = 3
+                (fn_args_average, 1.0),
+                (closure_args_average, 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn javascript_destructured_rest_nargs() {
+        check_metrics!(
+            "function f({ a, b }, [c, d], e = 1, ...rest) {}
+             const g = x => x;
+             const h = (a, { b }) => a;",
+            "foo.js",
+            JavascriptParser,
+            nargs,
+            [
+                (nargs, 7, usize),
+                (fn_args, 4, usize),
+                (closure_args, 3, usize)
+            ],
+            [
+                (fn_args_average, 4.0), // 1 function
+                (closure_args_average, 1.5) // 2 closures
+            ]
+        );
+    }
+
+    #[test]
+    fn typescript_optional_nargs() {
+        check_metrics!(
+            "function f(a: number, b?: string, ...c: number[]): void {}
+             const g = (x?: number) => x;",
+            "foo.ts",
+            TypescriptParser,
+            nargs,
+            [
+                (nargs, 4, usize),
+                (fn_args, 3, usize),
+                (closure_args, 1, usize)
+            ],
+            [(nargs_average, 2.0)] // 1 function + 1 closure = 2
+        );
+    }
+
+    #[test]
+    fn javascript_generators_nargs() {
+        check_metrics!(
+            "function* gen(a, b) {}
+             const g = function* (x) {};",
+            "foo.js",
+            JavascriptParser,
+            nargs,
+            [
+                (nargs, 3, usize),
+                (fn_args, 2, usize),
+                (closure_args, 1, usize)
+            ],
+            [
+                (fn_args_average, 2.0), // 1 function
+                (closure_args_average, 1.0) // 1 closure
+            ]
+        );
+    }
+
+    #[test]
+    fn typescript_generators_nargs() {
+        check_metrics!(
+            "function* gen(a: number, b: string) {}",
+            "foo.ts",
+            TypescriptParser,
+            nargs,
+            [
+                (nargs, 2, usize),
+                (fn_args, 2, usize),
+                (closure_args, 0, usize)
+            ],
+            [(fn_args_average, 2.0)] // 1 function
+        );
+    }
+
+    #[test]
+    fn java_methods_nargs() {
+        check_metrics!(
+            "class X {
+                 X(int a, int b) {}
+                 void f(int a, String... rest) {}
+                 void g() {}
+             }",
+            "foo.java",
+            JavaParser,
+            nargs,
+            [(nargs, 4, usize)],
+            [(nargs_average, 4.0 / 3.0)] // 3 functions
+        );
+    }
+
+    #[test]
+    fn java_lambdas_nargs() {
+        check_metrics!(
+            "class X {
+                 void f() {
+                     Function<Integer, Integer> g = x -> x + 1;
+                     BiFunction<Integer, Integer, Integer> h = (a, b) -> a + b;
+                     BiFunction<Integer, Integer, Integer> k = (Integer a, Integer b) -> a + b;
+                     Runnable r = () -> {};
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            nargs,
+            [
+                (nargs, 5, usize),
+                (fn_args, 0, usize),
+                (closure_args, 5, usize)
+            ],
+            [
+                (nargs_average, 1.0), // 1 function + 4 lambdas = 5
+                (closure_args_average, 1.25)
+            ]
+        );
+    }
+
+    #[test]
+    fn go_functions_nargs() {
+        check_metrics!(
+            "func f(a, b int, c string) {}
+             func (r *T) g(prefix string, values ...int) {}
+             func h(int, string) {}",
+            "foo.go",
+            GoParser,
+            nargs,
+            // The method receiver is not an argument
+            [(nargs, 7, usize)],
+            [(nargs_average, 7.0 / 3.0)] // 3 functions
+        );
+    }
+
+    #[test]
+    fn go_closures_nargs() {
+        check_metrics!(
+            "func f() {
+                 g := func(x, y int) int { return x + y }
+                 go func(s string) {}(\"a\")
+             }",
+            "foo.go",
+            GoParser,
+            nargs,
+            [
+                (nargs, 3, usize),
+                (fn_args, 0, usize),
+                (closure_args, 3, usize)
+            ],
+            [
+                (nargs_average, 1.0), // 1 function + 2 closures = 3
+                (closure_args_average, 1.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_methods_nargs() {
+        check_metrics!(
+            "class X {
+                 X(int a, int b) {}
+                 void F(int a, params int[] rest) {}
+                 void G(this string s, ref int a, out int b) {}
+             }",
+            "foo.cs",
+            CsharpParser,
+            nargs,
+            [(nargs, 7, usize)],
+            [(nargs_average, 7.0 / 3.0)] // 3 functions
+        );
+    }
+
+    #[test]
+    fn csharp_lambdas_nargs() {
+        check_metrics!(
+            "class X {
+                 void F() {
+                     Func<int, int> g = x => x + 1;
+                     Func<int, int, int> h = (a, b) => a + b;
+                     Func<int, int, int> k = (int a, int b) => a + b;
+                     Action r = () => {};
+                     Action<int> d = delegate (int v) {};
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            nargs,
+            [
+                (nargs, 6, usize),
+                (fn_args, 0, usize),
+                (closure_args, 6, usize)
+            ],
+            [
+                (nargs_average, 1.0), // 1 method + 5 closures = 6
+                (closure_args_average, 1.2)
+            ]
         );
     }
 }
@@ -1,4 +1,5 @@
 use fxhash::FxHashMap;
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
@@ -83,6 +84,31 @@ impl Serialize for Stats {
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            n1: f64,
+            #[serde(rename = "N1")]
+            big_n1: f64,
+            n2: f64,
+            #[serde(rename = "N2")]
+            big_n2: f64,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            u_operators: values.n1 as u64,
+            operators: values.big_n1 as u64,
+            u_operands: values.n2 as u64,
+            operands: values.big_n2 as u64,
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -325,9 +351,26 @@ impl Halstead for CppCode {
     }
 }
 
+impl Halstead for JavaCode {
+    fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>) {
+        compute_halstead::<Self>(node, code, halstead_maps);
+    }
+}
+
+impl Halstead for GoCode {
+    fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>) {
+        compute_halstead::<Self>(node, code, halstead_maps);
+    }
+}
+
+impl Halstead for CsharpCode {
+    fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>) {
+        compute_halstead::<Self>(node, code, halstead_maps);
+    }
+}
+
 impl Halstead for PreprocCode {}
 impl Halstead for CcommentCode {}
-impl Halstead for JavaCode {}
 
 #[cfg(test)]
 mod tests {
@@ -388,4 +431,64 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn java_operators_and_operands() {
+        check_metrics!(
+            "class X {
+                 int f(int a) {
+                     return a + 1;
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            halstead,
+            [
+                (u_operators, 6, usize), // {, int, (, return, +, ;
+                (operators, 8, usize),   // {, {, int, int, (, return, +, ;
+                (u_operands, 4, usize),  // X, f, a, 1
+                (operands, 5, usize)     // X, f, a, a, 1
+            ]
+        );
+    }
+
+    #[test]
+    fn go_operators_and_operands() {
+        check_metrics!(
+            "package main
+
+             func f(a int) int {
+                 return a + 1
+             }",
+            "foo.go",
+            GoParser,
+            halstead,
+            [
+                (u_operators, 6, usize), // package, func, (, {, return, +
+                (operators, 6, usize),
+                (u_operands, 5, usize), // main, f, a, int, 1
+                (operands, 7, usize)    // main, f, a, int, int, a, 1
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_operators_and_operands() {
+        check_metrics!(
+            "class X {
+                 int F(int a) {
+                     return a + 1;
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            halstead,
+            [
+                (u_operators, 6, usize), // {, int, (, return, +, ;
+                (operators, 8, usize),   // {, {, int, int, (, return, +, ;
+                (u_operands, 4, usize),  // X, F, a, 1
+                (operands, 5, usize)     // X, F, a, a, 1
+            ]
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use crate::checker::Checker;
 use fxhash::FxHashSet;
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
@@ -32,6 +33,31 @@ impl Serialize for Stats {
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            sloc: f64,
+            ploc: f64,
+            lloc: f64,
+            cloc: f64,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            start: 0,
+            end: values.sloc as usize,
+            unit: true,
+            lines: (0..values.ploc as usize).collect(),
+            logical_lines: values.lloc as usize,
+            comment_lines: values.cloc as usize,
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -363,9 +389,98 @@ impl Loc for CppCode {
     }
 }
 
+impl Loc for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
+        use Java::*;
+
+        let (start, end) = init(node, stats, is_func_space, is_unit);
+
+        match node.object().kind_id().into() {
+            StringLiteral | Program => {}
+            Comment => {
+                stats.comment_lines += (end - start) + 1;
+            }
+            ExpressionStatement | LocalVariableDeclaration | LabeledStatement | AssertStatement
+            | SwitchStatement | DoStatement | BreakStatement | ContinueStatement
+            | ReturnStatement | SynchronizedStatement | ThrowStatement | TryStatement
+            | TryWithResourcesStatement | IfStatement | WhileStatement | ForStatement
+            | EnhancedForStatement => {
+                stats.logical_lines += 1;
+            }
+            _ => {
+                stats.lines.insert(start);
+            }
+        }
+    }
+}
+
+impl Loc for GoCode {
+    fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
+        use crate::languages::Go::*;
+
+        let (start, end) = init(node, stats, is_func_space, is_unit);
+
+        match node.object().kind_id().into() {
+            InterpretedStringLiteral | RawStringLiteral | Block | SourceFile => {}
+            Comment => {
+                stats.comment_lines += (end - start) + 1;
+            }
+            SendStatement | IncStatement | DecStatement | AssignmentStatement
+            | ShortVarDeclaration | LabeledStatement | FallthroughStatement | BreakStatement
+            | ContinueStatement | GotoStatement | ReturnStatement | GoStatement
+            | DeferStatement | IfStatement | ForStatement | ExpressionSwitchStatement
+            | TypeSwitchStatement | SelectStatement | ConstDeclaration | VarDeclaration => {
+                stats.logical_lines += 1;
+            }
+            CallExpression => {
+                // Only a call used as a statement is a logical line,
+                // the grammar has no node for the expression statements
+                match node.object().parent().map(|parent| parent.kind_id().into()) {
+                    Some(Block) | Some(ExpressionCase) | Some(DefaultCase) | Some(TypeCase)
+                    | Some(CommunicationCase) | Some(LabeledStatement) => {
+                        stats.logical_lines += 1;
+                    }
+                    _ => {
+                        stats.lines.insert(start);
+                    }
+                }
+            }
+            _ => {
+                stats.lines.insert(start);
+            }
+        }
+    }
+}
+
+impl Loc for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
+        use Csharp::*;
+
+        let (start, end) = init(node, stats, is_func_space, is_unit);
+
+        match node.object().kind_id().into() {
+            StringLiteral | VerbatimStringLiteral | InterpolatedStringExpression
+            | CompilationUnit => {}
+            Comment => {
+                stats.comment_lines += (end - start) + 1;
+            }
+            ExpressionStatement | LocalDeclarationStatement | LabeledStatement
+            | BreakStatement | ContinueStatement | GotoStatement | ReturnStatement
+            | ThrowStatement | YieldStatement | TryStatement | CheckedStatement
+            | UnsafeStatement | FixedStatement | LockStatement | UsingStatement
+            | SwitchStatement | IfStatement | DoStatement | WhileStatement | ForStatement
+            | ForEachStatement => {
+                stats.logical_lines += 1;
+            }
+            _ => {
+                stats.lines.insert(start);
+            }
+        }
+    }
+}
+
 impl Loc for PreprocCode {}
 impl Loc for CcommentCode {}
-impl Loc for JavaCode {}
 
 #[cfg(test)]
 mod tests {
@@ -583,4 +698,113 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn java_general_loc() {
+        check_metrics!(
+            "class X {
+                 /* block
+                    comment */
+                 // line comment
+                 int f(int a) {
+
+                     int b = a + 1;
+                     if (b > 0) {
+                         return b;
+                     }
+                     return 0;
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            loc,
+            [
+                (sloc, 13, usize),
+                (ploc, 9, usize),
+                (lloc, 4, usize),
+                (cloc, 3, usize),
+                (blank, 1, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn go_general_loc() {
+        check_metrics!(
+            "package main
+
+             /* block
+                comment */
+             // line comment
+             func f(a int) int {
+
+                 b := a + 1
+                 if b > 0 {
+                     return b
+                 }
+                 return 0
+             }",
+            "foo.go",
+            GoParser,
+            loc,
+            [
+                (sloc, 13, usize),
+                (ploc, 8, usize),
+                (lloc, 4, usize),
+                (cloc, 3, usize),
+                (blank, 2, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn go_call_lloc() {
+        check_metrics!(
+            "package main
+
+             func f(a int) int {
+                 g(a)
+                 b := h(a, k(a))
+                 defer g(b)
+                 switch b {
+                 default:
+                     g(b)
+                 }
+                 return h(b, 0)
+             }",
+            "foo.go",
+            GoParser,
+            loc,
+            [(lloc, 6, usize)]
+        );
+    }
+
+    #[test]
+    fn csharp_general_loc() {
+        check_metrics!(
+            "class X {
+                 /* block
+                    comment */
+                 // line comment
+                 int F(int a) {
+
+                     int b = a + 1;
+                     if (b > 0) {
+                         return b;
+                     }
+                     return 0;
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            loc,
+            [
+                (sloc, 13, usize),
+                (ploc, 9, usize),
+                (lloc, 4, usize),
+                (cloc, 3, usize),
+                (blank, 1, usize)
+            ]
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
@@ -19,6 +20,9 @@ pub struct Stats {
     cyclomatic: f64,
     sloc: f64,
     comments_percentage: f64,
+    // The values read by the deserialization, since the inputs
+    // of the formulas cannot be obtained back from them
+    values: Option<(f64, f64, f64)>,
 }
 
 impl Serialize for Stats {
@@ -34,6 +38,30 @@ impl Serialize for Stats {
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            mi_original: Option<f64>,
+            mi_sei: Option<f64>,
+            mi_visual_studio: Option<f64>,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            values: Some((
+                values.mi_original.unwrap_or(f64::NAN),
+                values.mi_sei.unwrap_or(f64::NAN),
+                values.mi_visual_studio.unwrap_or(f64::NAN),
+            )),
+            ..Self::default()
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -54,6 +82,9 @@ impl Stats {
     /// Its value can be negative.
     #[inline(always)]
     pub fn mi_original(&self) -> f64 {
+        if let Some((mi_original, _, _)) = self.values {
+            return mi_original;
+        }
         // http://www.projectcodemeter.com/cost_estimation/help/GL_maintainability.htm
         171.0 - 5.2 * (self.halstead_volume).ln() - 0.23 * self.cyclomatic - 16.2 * self.sloc.ln()
     }
@@ -64,6 +95,9 @@ impl Stats {
     /// Its value can be negative.
     #[inline(always)]
     pub fn mi_sei(&self) -> f64 {
+        if let Some((_, mi_sei, _)) = self.values {
+            return mi_sei;
+        }
         // http://www.projectcodemeter.com/cost_estimation/help/GL_maintainability.htm
         171.0 - 5.2 * self.halstead_volume.log2() - 0.23 * self.cyclomatic - 16.2 * self.sloc.log2()
             + 50.0 * (self.comments_percentage * 2.4).sqrt().sin()
@@ -73,6 +107,9 @@ impl Stats {
     /// employed by Microsoft Visual Studio.
     #[inline(always)]
     pub fn mi_visual_studio(&self) -> f64 {
+        if let Some((_, _, mi_visual_studio)) = self.values {
+            return mi_visual_studio;
+        }
         // http://www.projectcodemeter.com/cost_estimation/help/GL_maintainability.htm
         let formula = 171.0
             - 5.2 * self.halstead_volume.ln()
@@ -112,6 +149,8 @@ impl Mi for TsxCode {}
 impl Mi for PreprocCode {}
 impl Mi for CcommentCode {}
 impl Mi for JavaCode {}
+impl Mi for GoCode {}
+impl Mi for CsharpCode {}
 
 #[cfg(test)]
 mod tests {
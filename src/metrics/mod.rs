@@ -1,4 +1,6 @@
+pub mod ck;
 pub mod cognitive;
+pub mod custom;
 pub mod cyclomatic;
 pub mod exit;
 pub mod fn_args;
@@ -6,3 +8,23 @@ pub mod halstead;
 pub mod loc;
 pub mod mi;
 pub mod nom;
+
+pub use custom::{CustomMetric, CustomMetrics};
+
+/// Returns the number of values an average has been computed on,
+/// given the sum of the values.
+///
+/// The number cannot be known when the sum is zero, while it is zero
+/// when the average is not a finite number.
+pub(crate) fn average_count(sum: f64, average: Option<f64>) -> Option<usize> {
+    match average {
+        Some(average) if average.is_finite() => {
+            if sum == 0. {
+                None
+            } else {
+                Some((sum / average).round() as usize)
+            }
+        }
+        _ => Some(0),
+    }
+}
@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::fmt;
@@ -26,6 +27,25 @@ impl Serialize for Stats {
     }
 }
 
+impl<'de> Deserialize<'de> for Stats {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values {
+            functions: f64,
+            closures: f64,
+        }
+
+        let values = Values::deserialize(deserializer)?;
+        Ok(Self {
+            functions: values.functions as usize,
+            closures: values.closures as usize,
+        })
+    }
+}
+
 impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -97,10 +117,10 @@ impl Nom for MozjsCode {
         use Mozjs::*;
 
         match node.object().kind_id().into() {
-            Function | FunctionDeclaration | MethodDefinition => {
+            Function | FunctionDeclaration | GeneratorFunctionDeclaration | MethodDefinition => {
                 stats.functions += 1;
             }
-            GeneratorFunction | GeneratorFunctionDeclaration | ArrowFunction => {
+            GeneratorFunction | ArrowFunction => {
                 stats.closures += 1;
             }
             _ => {}
@@ -113,10 +133,10 @@ impl Nom for JavascriptCode {
         use Javascript::*;
 
         match node.object().kind_id().into() {
-            Function | FunctionDeclaration | MethodDefinition => {
+            Function | FunctionDeclaration | GeneratorFunctionDeclaration | MethodDefinition => {
                 stats.functions += 1;
             }
-            GeneratorFunction | GeneratorFunctionDeclaration | ArrowFunction => {
+            GeneratorFunction | ArrowFunction => {
                 stats.closures += 1;
             }
             _ => {}
@@ -129,10 +149,10 @@ impl Nom for TypescriptCode {
         use Typescript::*;
 
         match node.object().kind_id().into() {
-            Function | FunctionDeclaration | MethodDefinition => {
+            Function | FunctionDeclaration | GeneratorFunctionDeclaration | MethodDefinition => {
                 stats.functions += 1;
             }
-            GeneratorFunction | GeneratorFunctionDeclaration | ArrowFunction => {
+            GeneratorFunction | ArrowFunction => {
                 stats.closures += 1;
             }
             _ => {}
@@ -145,10 +165,10 @@ impl Nom for TsxCode {
         use Tsx::*;
 
         match node.object().kind_id().into() {
-            Function | FunctionDeclaration | MethodDefinition => {
+            Function | FunctionDeclaration | GeneratorFunctionDeclaration | MethodDefinition => {
                 stats.functions += 1;
             }
-            GeneratorFunction | GeneratorFunctionDeclaration | ArrowFunction => {
+            GeneratorFunction | ArrowFunction => {
                 stats.closures += 1;
             }
             _ => {}
@@ -192,9 +212,62 @@ impl Nom for CppCode {
     }
 }
 
+impl Nom for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Java::*;
+
+        match node.object().kind_id().into() {
+            MethodDeclaration | ConstructorDeclaration => {
+                stats.functions += 1;
+            }
+            LambdaExpression => {
+                stats.closures += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Nom for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use crate::languages::Go::*;
+
+        match node.object().kind_id().into() {
+            FunctionDeclaration | MethodDeclaration => {
+                stats.functions += 1;
+            }
+            FuncLiteral => {
+                stats.closures += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Nom for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.object().kind_id().into() {
+            MethodDeclaration
+            | ConstructorDeclaration
+            | DestructorDeclaration
+            | OperatorDeclaration
+            | ConversionOperatorDeclaration
+            | LocalFunctionStatement
+            | AccessorDeclaration => {
+                stats.functions += 1;
+            }
+            LambdaExpression | AnonymousMethodExpression => {
+                stats.closures += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
 impl Nom for PreprocCode {}
 impl Nom for CcommentCode {}
-impl Nom for JavaCode {}
 
 #[cfg(test)]
 mod tests {
@@ -277,4 +350,70 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn java_nom() {
+        check_metrics!(
+            "class X {
+                 X() {}
+                 void a() {}
+                 void b() {
+                     Runnable r = () -> {};
+                 }
+             }",
+            "foo.java",
+            JavaParser,
+            nom,
+            [
+                (functions, 3, usize),
+                (closures, 1, usize),
+                (total, 4, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn go_nom() {
+        check_metrics!(
+            "func a() {}
+             func (t T) b() {}
+             func c() {
+                 go func() {}()
+                 defer func() {}()
+                 f := func(x int) int { return x }
+             }",
+            "foo.go",
+            GoParser,
+            nom,
+            [
+                (functions, 3, usize),
+                (closures, 3, usize),
+                (total, 6, usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn csharp_nom() {
+        check_metrics!(
+            "class X {
+                 X() {}
+                 ~X() {}
+                 int P { get { return 1; } set {} }
+                 void A() {
+                     int Local() => 1;
+                     Func<int> f = () => 1;
+                     Action d = delegate {};
+                 }
+             }",
+            "foo.cs",
+            CsharpParser,
+            nom,
+            [
+                (functions, 6, usize),
+                (closures, 2, usize),
+                (total, 8, usize)
+            ]
+        );
+    }
 }
@@ -1,8 +1,37 @@
 use tree_sitter::Node as OtherNode;
+use tree_sitter::TreeCursor;
 
 use crate::traits::Search;
 
 /// An `AST` node.
+///
+/// The positions of a node are pairs of a row and a column,
+/// both starting from 0, where the column is counted in bytes.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_code_analysis::{ParserTrait, PythonParser};
+///
+/// let source = b"def foo(x):\n    return x\n".to_vec();
+/// let parser = PythonParser::new(source, &PathBuf::from("foo.py"), None);
+/// let root = parser.get_root();
+///
+/// let function = root.children().next().unwrap();
+/// assert_eq!(function.kind(), "function_definition");
+///
+/// let name = function.child_by_field_name("name").unwrap();
+/// assert_eq!(name.utf8_text(parser.get_code()), Some("foo"));
+/// assert_eq!(name.start_position(), (0, 4));
+///
+/// let identifiers = root
+///     .traverse()
+///     .filter(|node| node.kind() == "identifier")
+///     .count();
+/// assert_eq!(identifiers, 3);
+/// ```
 #[derive(Clone, Copy)]
 pub struct Node<'a>(OtherNode<'a>);
 
@@ -13,6 +42,108 @@ impl<'a> Node<'a> {
         self.0.has_error()
     }
 
+    /// Checks if a node represents a syntax error.
+    pub fn is_error(&self) -> bool {
+        self.0.is_error()
+    }
+
+    /// Checks if a node has been inserted by the parser
+    /// to recover from a syntax error.
+    pub fn is_missing(&self) -> bool {
+        self.0.is_missing()
+    }
+
+    /// Checks if a node corresponds to a named rule of the grammar,
+    /// rather than to an anonymous token, e.g. a keyword or a punctuation.
+    pub fn is_named(&self) -> bool {
+        self.0.is_named()
+    }
+
+    /// Returns the kind of a node, i.e. the name of its grammar rule.
+    pub fn kind(&self) -> &'static str {
+        self.0.kind()
+    }
+
+    /// Returns the numeric identifier of the kind of a node, as the ones
+    /// contained in the language enums, e.g. [`Python`].
+    ///
+    /// [`Python`]: enum.Python.html
+    pub fn kind_id(&self) -> u16 {
+        self.0.kind_id()
+    }
+
+    /// Returns the byte offset where a node starts.
+    pub fn start_byte(&self) -> usize {
+        self.0.start_byte()
+    }
+
+    /// Returns the byte offset where a node ends.
+    pub fn end_byte(&self) -> usize {
+        self.0.end_byte()
+    }
+
+    /// Returns the row and the column where a node starts.
+    pub fn start_position(&self) -> (usize, usize) {
+        let point = self.0.start_position();
+        (point.row, point.column)
+    }
+
+    /// Returns the row and the column where a node ends.
+    pub fn end_position(&self) -> (usize, usize) {
+        let point = self.0.end_position();
+        (point.row, point.column)
+    }
+
+    /// Returns the text of a node, given the code which has been parsed,
+    /// or `None` if it is not a valid `UTF-8` text.
+    pub fn utf8_text<'b>(&self, code: &'b [u8]) -> Option<&'b str> {
+        self.0.utf8_text(code).ok()
+    }
+
+    /// Returns the parent of a node, if any.
+    pub fn parent(&self) -> Option<Node<'a>> {
+        self.0.parent().map(Node)
+    }
+
+    /// Returns the number of children of a node.
+    pub fn child_count(&self) -> usize {
+        self.0.child_count()
+    }
+
+    /// Returns the child of a node at the given index, if any.
+    pub fn child(&self, index: usize) -> Option<Node<'a>> {
+        self.0.child(index).map(Node)
+    }
+
+    /// Returns the child of a node associated to a field of its
+    /// grammar rule, e.g. the `name` of a function, if any.
+    pub fn child_by_field_name(&self, name: &str) -> Option<Node<'a>> {
+        self.0.child_by_field_name(name).map(Node)
+    }
+
+    /// Returns an iterator over the children of a node.
+    pub fn children(&self) -> impl Iterator<Item = Node<'a>> {
+        let mut cursor = self.0.walk();
+        let children: Vec<_> = self.0.children(&mut cursor).map(Node).collect();
+        children.into_iter()
+    }
+
+    /// Returns an iterator over the named children of a node,
+    /// skipping the anonymous tokens.
+    pub fn named_children(&self) -> impl Iterator<Item = Node<'a>> {
+        self.children().filter(|child| child.is_named())
+    }
+
+    /// Returns an iterator over a node and all its descendants,
+    /// visited depth-first in pre-order, i.e. in the order
+    /// they appear in the code.
+    pub fn traverse(&self) -> Traverse<'a> {
+        Traverse {
+            cursor: self.0.walk(),
+            done: false,
+        }
+    }
+
     pub(crate) fn new(node: OtherNode<'a>) -> Self {
         Node(node)
     }
@@ -22,6 +153,40 @@ impl<'a> Node<'a> {
     }
 }
 
+/// An iterator over a node and its descendants, visited depth-first.
+///
+/// It is returned by [`Node::traverse`].
+///
+/// [`Node::traverse`]: struct.Node.html#method.traverse
+pub struct Traverse<'a> {
+    cursor: TreeCursor<'a>,
+    done: bool,
+}
+
+impl<'a> Iterator for Traverse<'a> {
+    type Item = Node<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let node = Node(self.cursor.node());
+        // The cursor cannot move outside of the node where it started
+        if !self.cursor.goto_first_child() && !self.cursor.goto_next_sibling() {
+            loop {
+                if !self.cursor.goto_parent() {
+                    self.done = true;
+                    break;
+                }
+                if self.cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+        Some(node)
+    }
+}
+
 impl<'a> Search<'a> for Node<'a> {
     fn first_occurence(&self, pred: fn(u16) -> bool) -> Option<Node<'a>> {
         let mut cursor = self.0.walk();
@@ -92,3 +257,90 @@ impl<'a> Search<'a> for Node<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::*;
+
+    fn python_parser(source: &str) -> PythonParser {
+        let path = PathBuf::from("foo.py");
+        PythonParser::new(source.as_bytes().to_vec(), &path, None)
+    }
+
+    #[test]
+    fn node_positions() {
+        let parser = python_parser("x = 1\nif x:\n    y = x\n");
+        let root = parser.get_root();
+
+        let statement = root.child(1).unwrap();
+        assert_eq!(statement.kind(), "if_statement");
+        assert_eq!(statement.kind_id(), Python::IfStatement as u16);
+        assert_eq!(statement.start_byte(), 6);
+        assert_eq!(statement.end_byte(), 21);
+        assert_eq!(statement.start_position(), (1, 0));
+        assert_eq!(statement.end_position(), (2, 9));
+        assert_eq!(
+            statement.utf8_text(parser.get_code()),
+            Some("if x:\n    y = x")
+        );
+        assert_eq!(statement.parent().unwrap().kind(), "module");
+        assert!(root.parent().is_none());
+    }
+
+    #[test]
+    fn node_children() {
+        let parser = python_parser("def foo(a, b):\n    return a\n");
+        let root = parser.get_root();
+        let function = root.child(0).unwrap();
+
+        let kinds: Vec<_> = function.children().map(|child| child.kind()).collect();
+        assert_eq!(kinds, ["def", "identifier", "parameters", ":", "block"]);
+        assert_eq!(function.child_count(), kinds.len());
+
+        let named: Vec<_> = function
+            .named_children()
+            .map(|child| child.kind())
+            .collect();
+        assert_eq!(named, ["identifier", "parameters", "block"]);
+
+        let parameters = function.child_by_field_name("parameters").unwrap();
+        assert_eq!(parameters.utf8_text(parser.get_code()), Some("(a, b)"));
+        assert!(function.child_by_field_name("foo").is_none());
+    }
+
+    #[test]
+    fn node_traverse() {
+        let parser = python_parser("def foo(a):\n    return a\nb = 1\n");
+        let root = parser.get_root();
+
+        let identifiers: Vec<_> = root
+            .traverse()
+            .filter(|node| node.kind() == "identifier")
+            .filter_map(|node| node.utf8_text(parser.get_code()))
+            .collect();
+        assert_eq!(identifiers, ["foo", "a", "a", "b"]);
+
+        // The traversal does not leave the starting node
+        let function = root.child(0).unwrap();
+        let last = function.traverse().last().unwrap();
+        assert_eq!(last.utf8_text(parser.get_code()), Some("a"));
+        assert_eq!(last.start_position(), (1, 11));
+
+        let mut count = 0;
+        root.act_on_node(&mut |_| count += 1);
+        assert_eq!(root.traverse().count(), count);
+    }
+
+    #[test]
+    fn node_errors() {
+        let parser = python_parser("def foo(:\n    pass\n");
+        let root = parser.get_root();
+
+        assert!(root.has_error());
+        assert!(root
+            .traverse()
+            .any(|node| node.is_error() || node.is_missing()));
+    }
+}
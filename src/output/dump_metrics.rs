@@ -1,6 +1,8 @@
+use serde_json::Value;
 use std::io::Write;
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, StandardStreamLock, WriteColor};
 
+use crate::ck;
 use crate::cognitive;
 use crate::cyclomatic;
 use crate::exit;
@@ -10,7 +12,20 @@ use crate::loc;
 use crate::mi;
 use crate::nom;
 
-use crate::spaces::{CodeMetrics, FuncSpace};
+use crate::spaces::{CodeMetrics, FuncSpace, Metric};
+
+/// The order in which the metrics are dumped.
+const METRICS: [Metric; 9] = [
+    Metric::Cognitive,
+    Metric::Cyclomatic,
+    Metric::NArgs,
+    Metric::NExits,
+    Metric::Halstead,
+    Metric::Loc,
+    Metric::Nom,
+    Metric::Mi,
+    Metric::Ck,
+];
 
 /// Dumps the metrics of a code.
 ///
@@ -67,7 +82,17 @@ fn dump_space(
     write!(stdout, "{}", space.name.as_ref().map_or("", |name| &name))?;
 
     color!(stdout, Red, true);
-    writeln!(stdout, " (@{})", space.start_line)?;
+    write!(stdout, " (@{})", space.start_line)?;
+
+    if space.recursive {
+        color!(stdout, Magenta, true);
+        write!(stdout, " recursive")?;
+    }
+    if space.parse_errors > 0 {
+        color!(stdout, Red, true);
+        write!(stdout, " {} parse errors", space.parse_errors)?;
+    }
+    writeln!(stdout)?;
 
     let prefix = format!("{}{}", prefix, pref_child);
     dump_metrics(&space.metrics, &prefix, space.spaces.is_empty(), stdout)?;
@@ -97,14 +122,31 @@ fn dump_metrics(
     writeln!(stdout, "metrics")?;
 
     let prefix = format!("{}{}", prefix, pref_child);
-    dump_cognitive(&metrics.cognitive, &prefix, false, stdout)?;
-    dump_cyclomatic(&metrics.cyclomatic, &prefix, false, stdout)?;
-    dump_nargs(&metrics.nargs, &prefix, false, stdout)?;
-    dump_nexits(&metrics.nexits, &prefix, false, stdout)?;
-    dump_halstead(&metrics.halstead, &prefix, false, stdout)?;
-    dump_loc(&metrics.loc, &prefix, false, stdout)?;
-    dump_nom(&metrics.nom, &prefix, false, stdout)?;
-    dump_mi(&metrics.mi, &prefix, true, stdout)
+    let selected: Vec<_> = METRICS
+        .iter()
+        .filter(|metric| metrics.is_selected(**metric))
+        .collect();
+    for (i, metric) in selected.iter().enumerate() {
+        let last = i + 1 == selected.len() && metrics.custom.is_empty();
+        match metric {
+            Metric::Cognitive => dump_cognitive(&metrics.cognitive, &prefix, last, stdout)?,
+            Metric::Cyclomatic => dump_cyclomatic(&metrics.cyclomatic, &prefix, last, stdout)?,
+            Metric::NArgs => dump_nargs(&metrics.nargs, &prefix, last, stdout)?,
+            Metric::NExits => dump_nexits(&metrics.nexits, &prefix, last, stdout)?,
+            Metric::Halstead => dump_halstead(&metrics.halstead, &prefix, last, stdout)?,
+            Metric::Loc => dump_loc(&metrics.loc, &prefix, last, stdout)?,
+            Metric::Nom => dump_nom(&metrics.nom, &prefix, last, stdout)?,
+            Metric::Mi => dump_mi(&metrics.mi, &prefix, last, stdout)?,
+            Metric::Ck => dump_ck(&metrics.ck, &prefix, last, stdout)?,
+        }
+    }
+
+    let last_index = metrics.custom.len().saturating_sub(1);
+    for (i, (name, value)) in metrics.custom.iter().enumerate() {
+        dump_custom(name, value, &prefix, i == last_index, stdout)?;
+    }
+
+    Ok(())
 }
 
 fn dump_cognitive(
@@ -124,7 +166,45 @@ fn dump_cognitive(
     let prefix = format!("{}{}", prefix, pref_child);
 
     dump_value("sum", stats.cognitive(), &prefix, false, stdout)?;
-    dump_value("average", stats.cognitive_average(), &prefix, true, stdout)
+
+    let increments = match stats.increments() {
+        Some(increments) => increments,
+        None => return dump_value("average", stats.cognitive_average(), &prefix, true, stdout),
+    };
+
+    dump_value("average", stats.cognitive_average(), &prefix, false, stdout)?;
+
+    color!(stdout, Blue);
+    write!(stdout, "{}`- ", prefix)?;
+
+    color!(stdout, Green, true);
+    writeln!(stdout, "increments")?;
+
+    let prefix = format!("{}   ", prefix);
+    let last_index = increments.len().saturating_sub(1);
+    for (i, inc) in increments.iter().enumerate() {
+        let pref = if i == last_index { "`- " } else { "|- " };
+
+        color!(stdout, Blue);
+        write!(stdout, "{}{}", prefix, pref)?;
+
+        color!(stdout, Magenta, true);
+        write!(stdout, "{}: ", inc.kind)?;
+
+        color!(stdout, White);
+        writeln!(
+            stdout,
+            "+{} (nesting {}) at ({}, {}) - ({}, {})",
+            inc.increment,
+            inc.nesting,
+            inc.start_line,
+            inc.start_column,
+            inc.end_line,
+            inc.end_column
+        )?;
+    }
+
+    Ok(())
 }
 
 fn dump_cyclomatic(
@@ -228,6 +308,30 @@ fn dump_nom(
     dump_value("total", stats.total(), &prefix, true, stdout)
 }
 
+fn dump_ck(
+    stats: &ck::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color!(stdout, Blue);
+    write!(stdout, "{}{}", prefix, pref)?;
+
+    color!(stdout, Green, true);
+    writeln!(stdout, "ck")?;
+
+    let prefix = format!("{}{}", prefix, pref_child);
+    dump_value("classes", stats.classes(), &prefix, false, stdout)?;
+    dump_value("wmc", stats.wmc(), &prefix, false, stdout)?;
+    dump_value("dit", stats.dit(), &prefix, false, stdout)?;
+    dump_value("noc", stats.noc(), &prefix, false, stdout)?;
+    dump_value("cbo", stats.cbo(), &prefix, false, stdout)?;
+    dump_value("rfc", stats.rfc(), &prefix, false, stdout)?;
+    dump_value("lcom", stats.lcom(), &prefix, true, stdout)
+}
+
 fn dump_mi(
     stats: &mi::Stats,
     prefix: &str,
@@ -260,16 +364,18 @@ fn dump_nargs(
     last: bool,
     stdout: &mut StandardStreamLock,
 ) -> std::io::Result<()> {
-    let pref = if last { "`- " } else { "|- " };
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
 
     color!(stdout, Blue);
     write!(stdout, "{}{}", prefix, pref)?;
 
     color!(stdout, Green, true);
-    write!(stdout, "nargs: ")?;
+    writeln!(stdout, "nargs")?;
 
-    color!(stdout, White);
-    writeln!(stdout, "{}", stats.nargs())
+    let prefix = format!("{}{}", prefix, pref_child);
+    dump_value("functions", stats.fn_args(), &prefix, false, stdout)?;
+    dump_value("closures", stats.closure_args(), &prefix, false, stdout)?;
+    dump_value("total", stats.nargs(), &prefix, true, stdout)
 }
 
 fn dump_nexits(
@@ -290,6 +396,44 @@ fn dump_nexits(
     writeln!(stdout, "{}", stats.exit())
 }
 
+fn dump_custom(
+    name: &str,
+    value: &Value,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color!(stdout, Blue);
+    write!(stdout, "{}{}", prefix, pref)?;
+
+    let map = match value {
+        Value::Object(map) => map,
+        value => {
+            color!(stdout, Magenta, true);
+            write!(stdout, "{}: ", name)?;
+
+            color!(stdout, White);
+            return match value {
+                Value::String(value) => writeln!(stdout, "{}", value),
+                value => writeln!(stdout, "{}", value),
+            };
+        }
+    };
+
+    color!(stdout, Green, true);
+    writeln!(stdout, "{}", name)?;
+
+    let prefix = format!("{}{}", prefix, pref_child);
+    let last_index = map.len().saturating_sub(1);
+    for (i, (name, value)) in map.iter().enumerate() {
+        dump_custom(name, value, &prefix, i == last_index, stdout)?;
+    }
+
+    Ok(())
+}
+
 fn dump_value(
     name: &str,
     val: f64,
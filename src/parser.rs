@@ -76,6 +76,7 @@ impl<T: 'static + TSLanguage + Checker + Getter + Alterator + CodeMetricsT> Pars
     type Mi = T;
     type NArgs = T;
     type Exit = T;
+    type Ck = T;
 
     fn new(code: Vec<u8>, path: &PathBuf, pr: Option<Arc<PreprocResults>>) -> Self {
         let mut parser = TSParser::new();
@@ -1,12 +1,18 @@
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use crate::checker::Checker;
 use crate::node::Node;
 
+use crate::ck::{self, Ck};
 use crate::cognitive::{self, Cognitive};
+use crate::custom::{self, CustomMetrics};
 use crate::cyclomatic::{self, Cyclomatic};
+use crate::error::Error;
 use crate::exit::{self, Exit};
 use crate::fn_args::{self, NArgs};
 use crate::getter::Getter;
@@ -19,7 +25,7 @@ use crate::dump_metrics::*;
 use crate::traits::*;
 
 /// The list of supported space kinds.
-#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SpaceKind {
     /// An unknown space
@@ -32,11 +38,17 @@ pub enum SpaceKind {
     Struct,
     /// A `Rust` trait space
     Trait,
+    /// An interface space
+    Interface,
+    /// An enum space
+    Enum,
+    /// A `C#` record space
+    Record,
     /// A `Rust` implementation space
     Impl,
     /// A general space
     Unit,
-    /// A `C/C++` namespace
+    /// A `C/C++` or `C#` namespace
     Namespace,
 }
 
@@ -48,6 +60,9 @@ impl fmt::Display for SpaceKind {
             SpaceKind::Class => "class",
             SpaceKind::Struct => "struct",
             SpaceKind::Trait => "trait",
+            SpaceKind::Interface => "interface",
+            SpaceKind::Enum => "enum",
+            SpaceKind::Record => "record",
             SpaceKind::Impl => "impl",
             SpaceKind::Unit => "unit",
             SpaceKind::Namespace => "namespace",
@@ -56,8 +71,156 @@ impl fmt::Display for SpaceKind {
     }
 }
 
+impl FromStr for SpaceKind {
+    type Err = String;
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        match kind {
+            "unknown" => Ok(SpaceKind::Unknown),
+            "function" => Ok(SpaceKind::Function),
+            "class" => Ok(SpaceKind::Class),
+            "struct" => Ok(SpaceKind::Struct),
+            "trait" => Ok(SpaceKind::Trait),
+            "interface" => Ok(SpaceKind::Interface),
+            "enum" => Ok(SpaceKind::Enum),
+            "record" => Ok(SpaceKind::Record),
+            "impl" => Ok(SpaceKind::Impl),
+            "unit" => Ok(SpaceKind::Unit),
+            "namespace" => Ok(SpaceKind::Namespace),
+            kind => Err(format!("{:?} is not a space kind", kind)),
+        }
+    }
+}
+
+/// The list of supported metrics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Metric {
+    /// The number of arguments
+    NArgs,
+    /// The number of exit points
+    NExits,
+    /// The `Cognitive Complexity`
+    Cognitive,
+    /// The `Cyclomatic Complexity`
+    Cyclomatic,
+    /// The `Halstead` suite
+    Halstead,
+    /// The lines of code
+    Loc,
+    /// The number of methods
+    Nom,
+    /// The `Maintainability Index`
+    Mi,
+    /// The `Chidamber-Kemerer` suite
+    Ck,
+}
+
+impl Metric {
+    /// Returns all the supported metrics.
+    pub fn all() -> &'static [Metric] {
+        &[
+            Metric::NArgs,
+            Metric::NExits,
+            Metric::Cognitive,
+            Metric::Cyclomatic,
+            Metric::Halstead,
+            Metric::Loc,
+            Metric::Nom,
+            Metric::Mi,
+            Metric::Ck,
+        ]
+    }
+
+    // The metrics needed to compute a metric
+    fn dependencies(self) -> &'static [Metric] {
+        match self {
+            // The averages are computed on the number of methods
+            Metric::NArgs | Metric::NExits | Metric::Cognitive => &[Metric::Nom],
+            Metric::Mi => &[Metric::Loc, Metric::Cyclomatic, Metric::Halstead],
+            // The weighted methods per class are their cyclomatic complexities
+            Metric::Ck => &[Metric::Cyclomatic],
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Metric::NArgs => "nargs",
+            Metric::NExits => "nexits",
+            Metric::Cognitive => "cognitive",
+            Metric::Cyclomatic => "cyclomatic",
+            Metric::Halstead => "halstead",
+            Metric::Loc => "loc",
+            Metric::Nom => "nom",
+            Metric::Mi => "mi",
+            Metric::Ck => "ck",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(metric: &str) -> Result<Self, Self::Err> {
+        Metric::all()
+            .iter()
+            .find(|m| m.to_string() == metric)
+            .copied()
+            .ok_or_else(|| {
+                let names: Vec<_> = Metric::all().iter().map(|m| m.to_string()).collect();
+                format!(
+                    "{:?} is not a metric, expected one of: {}",
+                    metric,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+// A set of metrics
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Selection(u16);
+
+impl Default for Selection {
+    fn default() -> Self {
+        Self::new(Metric::all())
+    }
+}
+
+impl Selection {
+    fn new(metrics: &[Metric]) -> Self {
+        Self(
+            metrics
+                .iter()
+                .fold(0, |selection, metric| selection | 1 << *metric as u16),
+        )
+    }
+
+    #[inline(always)]
+    fn contains(self, metric: Metric) -> bool {
+        self.0 & 1 << metric as u16 != 0
+    }
+
+    // Adds the metrics needed to compute the selected ones
+    fn with_dependencies(self) -> Self {
+        let mut selection = self;
+        for metric in Metric::all().iter().filter(|m| self.contains(**m)) {
+            selection.0 |= Self::new(metric.dependencies()).0;
+        }
+        selection
+    }
+}
+
 /// All metrics data.
-#[derive(Debug, Clone, Serialize)]
+///
+/// Only the selected metrics, see [`MetricsOptions`], are serialized.
+///
+/// [`MetricsOptions`]: struct.MetricsOptions.html
+#[derive(Debug, Clone)]
 pub struct CodeMetrics {
     /// `NArgs` data
     pub nargs: fn_args::Stats,
@@ -74,6 +237,97 @@ pub struct CodeMetrics {
     pub nom: nom::Stats,
     /// `Mi` data
     pub mi: mi::Stats,
+    /// `Ck` data
+    pub ck: ck::Stats,
+    /// The data of the custom metrics, indexed by their names
+    pub custom: BTreeMap<String, Value>,
+    selection: Selection,
+}
+
+// The serialized metrics, the unselected ones being omitted
+#[derive(Serialize, Deserialize)]
+struct CodeMetricsData<NArgs, NExits, Cognitive, Cyclomatic, Halstead, Loc, Nom, Mi, Ck, Custom> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nargs: Option<NArgs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nexits: Option<NExits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cognitive: Option<Cognitive>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cyclomatic: Option<Cyclomatic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    halstead: Option<Halstead>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<Loc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nom: Option<Nom>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mi: Option<Mi>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ck: Option<Ck>,
+    #[serde(flatten)]
+    custom: Custom,
+}
+
+type DeserializedMetrics = CodeMetricsData<
+    fn_args::Stats,
+    exit::Stats,
+    cognitive::Stats,
+    cyclomatic::Stats,
+    halstead::Stats,
+    loc::Stats,
+    nom::Stats,
+    mi::Stats,
+    ck::Stats,
+    BTreeMap<String, Value>,
+>;
+
+impl Serialize for CodeMetrics {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let selected = |metric| self.selection.contains(metric);
+        CodeMetricsData {
+            nargs: Some(&self.nargs).filter(|_| selected(Metric::NArgs)),
+            nexits: Some(&self.nexits).filter(|_| selected(Metric::NExits)),
+            cognitive: Some(&self.cognitive).filter(|_| selected(Metric::Cognitive)),
+            cyclomatic: Some(&self.cyclomatic).filter(|_| selected(Metric::Cyclomatic)),
+            halstead: Some(&self.halstead).filter(|_| selected(Metric::Halstead)),
+            loc: Some(&self.loc).filter(|_| selected(Metric::Loc)),
+            nom: Some(&self.nom).filter(|_| selected(Metric::Nom)),
+            mi: Some(&self.mi).filter(|_| selected(Metric::Mi)),
+            ck: Some(&self.ck).filter(|_| selected(Metric::Ck)),
+            custom: &self.custom,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CodeMetrics {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = DeserializedMetrics::deserialize(deserializer)?;
+
+        // The metrics which are missing have not been selected
+        fn take<T: Default>(stats: Option<T>, metric: Metric, metrics: &mut Vec<Metric>) -> T {
+            if stats.is_some() {
+                metrics.push(metric);
+            }
+            stats.unwrap_or_default()
+        }
+
+        let mut metrics = Vec::new();
+        Ok(Self {
+            nargs: take(data.nargs, Metric::NArgs, &mut metrics),
+            nexits: take(data.nexits, Metric::NExits, &mut metrics),
+            cognitive: take(data.cognitive, Metric::Cognitive, &mut metrics),
+            cyclomatic: take(data.cyclomatic, Metric::Cyclomatic, &mut metrics),
+            halstead: take(data.halstead, Metric::Halstead, &mut metrics),
+            loc: take(data.loc, Metric::Loc, &mut metrics),
+            nom: take(data.nom, Metric::Nom, &mut metrics),
+            mi: take(data.mi, Metric::Mi, &mut metrics),
+            ck: take(data.ck, Metric::Ck, &mut metrics),
+            custom: data.custom,
+            selection: Selection::new(&metrics),
+        })
+    }
 }
 
 impl Default for CodeMetrics {
@@ -87,24 +341,86 @@ impl Default for CodeMetrics {
             mi: mi::Stats::default(),
             nargs: fn_args::Stats::default(),
             nexits: exit::Stats::default(),
+            ck: ck::Stats::default(),
+            custom: BTreeMap::new(),
+            selection: Selection::default(),
         }
     }
 }
 
 impl fmt::Display for CodeMetrics {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "{}", self.nargs)?;
-        writeln!(f, "{}", self.nexits)?;
-        writeln!(f, "{}", self.cognitive)?;
-        writeln!(f, "{}", self.cyclomatic)?;
-        writeln!(f, "{}", self.halstead)?;
-        writeln!(f, "{}", self.loc)?;
-        writeln!(f, "{}", self.nom)?;
-        write!(f, "{}", self.mi)
+        let stats: [(Metric, &dyn fmt::Display); 9] = [
+            (Metric::NArgs, &self.nargs),
+            (Metric::NExits, &self.nexits),
+            (Metric::Cognitive, &self.cognitive),
+            (Metric::Cyclomatic, &self.cyclomatic),
+            (Metric::Halstead, &self.halstead),
+            (Metric::Loc, &self.loc),
+            (Metric::Nom, &self.nom),
+            (Metric::Mi, &self.mi),
+            (Metric::Ck, &self.ck),
+        ];
+        let mut lines: Vec<_> = stats
+            .iter()
+            .filter(|(metric, _)| self.is_selected(*metric))
+            .map(|(_, stats)| stats.to_string())
+            .collect();
+        for (name, value) in &self.custom {
+            lines.push(format!("{}: {}", name, value));
+        }
+        write!(f, "{}", lines.join("\n"))
     }
 }
 
 impl CodeMetrics {
+    /// Creates empty metrics data, where only the given metrics are selected.
+    pub fn with_metrics(metrics: &[Metric]) -> Self {
+        Self {
+            selection: Selection::new(metrics),
+            ..Self::default()
+        }
+    }
+
+    /// Checks if a metric has been selected to be computed.
+    pub fn is_selected(&self, metric: Metric) -> bool {
+        self.selection.contains(metric)
+    }
+
+    /// Returns the values of the metrics, named as in their serialization,
+    /// e.g. `cyclomatic.sum`, and sorted by name.
+    ///
+    /// The values which are not defined, e.g. the averages of a space
+    /// without functions, are `NaN`, while the lists of values,
+    /// such as the increments of the `Cognitive Complexity`, are skipped.
+    pub fn flat_values(&self) -> Vec<(String, f64)> {
+        fn flatten(prefix: &str, value: &Value, values: &mut Vec<(String, f64)>) {
+            match value {
+                Value::Object(map) => {
+                    for (key, value) in map {
+                        let name = if prefix.is_empty() {
+                            key.to_string()
+                        } else {
+                            format!("{}.{}", prefix, key)
+                        };
+                        flatten(&name, value, values);
+                    }
+                }
+                Value::Number(number) => {
+                    values.push((prefix.to_string(), number.as_f64().unwrap_or(f64::NAN)))
+                }
+                Value::Null => values.push((prefix.to_string(), f64::NAN)),
+                _ => {}
+            }
+        }
+
+        let mut values = Vec::new();
+        if let Ok(value) = serde_json::to_value(self) {
+            flatten("", &value, &mut values);
+        }
+        values
+    }
+
     pub fn merge(&mut self, other: &CodeMetrics) {
         self.cognitive.merge(&other.cognitive);
         self.cyclomatic.merge(&other.cyclomatic);
@@ -114,11 +430,105 @@ impl CodeMetrics {
         self.mi.merge(&other.mi);
         self.nargs.merge(&other.nargs);
         self.nexits.merge(&other.nexits);
+        self.ck.merge(&other.ck);
+    }
+}
+
+/// A syntax error found by the parser.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParseError {
+    /// If `true`, the node has been inserted by the parser to recover
+    /// from an error, e.g. a missing parenthesis, otherwise the node
+    /// contains some code which cannot be parsed
+    pub missing: bool,
+    /// The kind of the node, e.g. `ERROR` or `)` for a missing parenthesis
+    pub kind: String,
+    /// The first line of the node
+    pub start_line: usize,
+    /// The first column of the node
+    pub start_column: usize,
+    /// The last line of the node
+    pub end_line: usize,
+    /// The column following the last character of the node, so it is
+    /// equal to `start_column` for a missing node, which is empty
+    pub end_column: usize,
+}
+
+impl ParseError {
+    fn new(node: &Node) -> Self {
+        let start = node.object().start_position();
+        let end = node.object().end_position();
+        Self {
+            missing: node.is_missing(),
+            kind: node.kind().to_string(),
+            start_line: start.row + 1,
+            start_column: start.column + 1,
+            end_line: end.row + 1,
+            end_column: end.column + 1,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.missing {
+            write!(f, "missing `{}`", self.kind)?;
+        } else {
+            write!(f, "syntax error")?;
+        }
+        write!(
+            f,
+            " at {}:{}-{}:{}",
+            self.start_line, self.start_column, self.end_line, self.end_column
+        )
     }
 }
 
+/// A call made in a function space.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Call {
+    /// The name of the called function, which is the last component
+    /// of a qualified callee, e.g. `f` for `a.f()` or `a::f()`
+    pub name: String,
+    /// The receiver or the path qualifying the callee, if any,
+    /// e.g. `a` for `a.f()` or `a::f()`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<String>,
+    /// The line of the call
+    pub line: usize,
+}
+
+impl Call {
+    fn new(node: &Node, name: &str, receiver: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            receiver: receiver.map(|receiver| receiver.to_string()),
+            line: node.object().start_position().row + 1,
+        }
+    }
+
+    /// Checks if the callee is not qualified, e.g. `f()`.
+    pub fn is_unqualified(&self) -> bool {
+        self.receiver.is_none()
+    }
+
+    /// Checks if the callee belongs to the object, or to the type, of the caller,
+    /// e.g. `self.f()`, `this.f()` or `Self::f()`.
+    pub fn is_on_self(&self) -> bool {
+        matches!(
+            self.receiver.as_deref(),
+            Some("self") | Some("this") | Some("Self")
+        )
+    }
+}
+
+#[inline(always)]
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
 /// Function space data.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FuncSpace {
     /// The name of a function space
     ///
@@ -131,6 +541,25 @@ pub struct FuncSpace {
     pub end_line: usize,
     /// The space kind
     pub kind: SpaceKind,
+    /// If `true`, the function space is directly or mutually recursive
+    /// with other functions contained in the same file
+    #[serde(default)]
+    pub recursive: bool,
+    /// The number of syntax errors found by the parser in a function space,
+    /// including the ones of its subspaces
+    ///
+    /// The metrics of a space containing some errors could be wrong,
+    /// since the parser skips or inserts some nodes to recover from them.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub parse_errors: usize,
+    /// The syntax errors found by the parser in a function space,
+    /// excluding the ones of its subspaces
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ParseError>,
+    /// The calls made in a function space, excluding the ones
+    /// of its subspaces
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calls: Vec<Call>,
     /// All subspaces contained in a function space
     pub spaces: Vec<FuncSpace>,
     /// All metrics of a function space
@@ -138,7 +567,13 @@ pub struct FuncSpace {
 }
 
 impl FuncSpace {
-    fn new<T: Getter>(node: &Node, code: &[u8], kind: SpaceKind) -> Self {
+    fn new<T: Getter>(
+        node: &Node,
+        code: &[u8],
+        kind: SpaceKind,
+        options: &MetricsOptions,
+        selection: Selection,
+    ) -> Self {
         let (start_position, end_position) = match kind {
             SpaceKind::Unit => {
                 if node.object().child_count() == 0 {
@@ -155,11 +590,22 @@ impl FuncSpace {
                 node.object().end_position().row + 1,
             ),
         };
+        let mut metrics = CodeMetrics {
+            selection,
+            ..CodeMetrics::default()
+        };
+        if options.cognitive_increments {
+            metrics.cognitive.record_increments();
+        }
         Self {
             name: T::get_func_space_name(&node, code).map(|name| name.to_string()),
             spaces: Vec::new(),
-            metrics: CodeMetrics::default(),
+            metrics,
             kind,
+            recursive: false,
+            parse_errors: 0,
+            errors: Vec::new(),
+            calls: Vec::new(),
             start_line: start_position,
             end_line: end_position,
         }
@@ -181,16 +627,28 @@ fn compute_halstead_and_mi<T: ParserTrait>(state: &mut State) {
 
 #[inline(always)]
 fn compute_averages(state: &mut State) {
+    let nom_functions = state.space.metrics.nom.functions() as usize;
+    let nom_closures = state.space.metrics.nom.closures() as usize;
     let nom_total = state.space.metrics.nom.total() as usize;
     // Cognitive average
     state.space.metrics.cognitive.finalize(nom_total);
     // Nexit average
     state.space.metrics.nexits.finalize(nom_total);
     // Nargs average
-    state.space.metrics.nargs.finalize(nom_total);
+    state.space.metrics.nargs.finalize(nom_functions, nom_closures);
+}
+
+#[inline(always)]
+fn compute_ck(state: &mut State) {
+    let space = &mut state.space;
+    space.metrics.ck.finalize(space.kind, space.name.as_deref());
 }
 
-fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
+fn finalize<T: ParserTrait>(
+    state_stack: &mut Vec<State>,
+    diff_level: usize,
+    custom: &CustomMetrics,
+) {
     if state_stack.is_empty() {
         return;
     }
@@ -199,27 +657,40 @@ fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
             let mut last_state = state_stack.last_mut().unwrap();
             compute_halstead_and_mi::<T>(&mut last_state);
             compute_averages(&mut last_state);
+            compute_ck(last_state);
             break;
         } else {
             let mut state = state_stack.pop().unwrap();
             compute_halstead_and_mi::<T>(&mut state);
             compute_averages(&mut state);
+            compute_ck(&mut state);
+            custom.finalize(&mut state.space, &mut state.custom);
 
             let mut last_state = state_stack.last_mut().unwrap();
             last_state.halstead_maps.merge(&state.halstead_maps);
             compute_halstead_and_mi::<T>(&mut last_state);
+            custom.merge(&mut last_state.custom, &state.custom);
+
+            // Methods are needed to compute the metrics of their class
+            last_state.space.metrics.ck.add_method(
+                state.space.name.as_deref(),
+                state.space.metrics.cyclomatic.cyclomatic(),
+                &state.space.metrics.ck,
+            );
 
             // Merge function spaces
             last_state.space.metrics.merge(&state.space.metrics);
+            last_state.space.parse_errors += state.space.parse_errors;
             last_state.space.spaces.push(state.space);
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct State<'a> {
     space: FuncSpace,
     halstead_maps: HalsteadMaps<'a>,
+    custom: custom::Stats,
 }
 
 /// Returns all function spaces data of a code. This function needs a parser to
@@ -245,6 +716,88 @@ struct State<'a> {
 /// metrics(&parser, &path).unwrap();
 /// ```
 pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a PathBuf) -> Option<FuncSpace> {
+    metrics_with_options(parser, path, &MetricsOptions::default())
+}
+
+/// Returns all function spaces data of a code, failing when the code
+/// contains syntax errors. This function needs a parser to be created
+/// a priori in order to work.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_code_analysis::{try_metrics, Error, ParserTrait, PythonParser};
+///
+/// let path = PathBuf::from("foo.py");
+///
+/// let parser = PythonParser::new(b"def foo():\n    pass\n".to_vec(), &path, None);
+/// assert!(try_metrics(&parser, &path).is_ok());
+///
+/// let parser = PythonParser::new(b"def foo(:\n    pass\n".to_vec(), &path, None);
+/// assert!(matches!(
+///     try_metrics(&parser, &path),
+///     Err(Error::Parse { .. })
+/// ));
+/// ```
+pub fn try_metrics<'a, T: ParserTrait>(
+    parser: &'a T,
+    path: &'a PathBuf,
+) -> Result<FuncSpace, Error> {
+    check_parse(parser)?;
+    metrics(parser, path).ok_or(Error::MetricsNotSupported)
+}
+
+// Fails when the parse of a code contains some syntax errors
+pub(crate) fn check_parse<T: ParserTrait>(parser: &T) -> Result<(), Error> {
+    let root = parser.get_root();
+    if root.has_error() {
+        let errors = root
+            .traverse()
+            .filter(|node| node.is_error() || node.is_missing())
+            .count();
+        Err(Error::Parse { errors })
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns all function spaces data of a code, computed according to
+/// the given options. This function needs a parser to be created a priori
+/// in order to work.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_code_analysis::{metrics_with_options, MetricsOptions, ParserTrait, PythonParser};
+///
+/// let source_code = "def f(a):\n    if a:\n        return 1\n";
+///
+/// // The path to a dummy file used to contain the source code
+/// let path = PathBuf::from("foo.py");
+/// let source_as_vec = source_code.as_bytes().to_vec();
+///
+/// // The parser of the code, in this case a Python parser
+/// let parser = PythonParser::new(source_as_vec, &path, None);
+///
+/// // Records every increment of the cognitive complexity
+/// let options = MetricsOptions {
+///     cognitive_increments: true,
+///     ..Default::default()
+/// };
+///
+/// // Gets all function spaces data of the code contained in foo.py
+/// let space = metrics_with_options(&parser, &path, &options).unwrap();
+/// assert!(space.spaces[0].metrics.cognitive.increments().is_some());
+/// ```
+pub fn metrics_with_options<'a, T: ParserTrait>(
+    parser: &'a T,
+    path: &'a PathBuf,
+    options: &MetricsOptions,
+) -> Option<FuncSpace> {
     let code = parser.get_code();
     let node = parser.get_root();
     let mut cursor = node.object().walk();
@@ -253,11 +806,17 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a PathBuf) -> Option<F
     let mut state_stack: Vec<State> = Vec::new();
     let mut last_level = 0;
 
+    let selection = options
+        .metrics
+        .as_deref()
+        .map_or_else(Selection::default, Selection::new);
+    let computed = selection.with_dependencies();
+
     stack.push((node, 0));
 
     while let Some((node, level)) = stack.pop() {
         if level < last_level {
-            finalize::<T>(&mut state_stack, last_level - level);
+            finalize::<T>(&mut state_stack, last_level - level, &options.custom);
             last_level = level;
         }
 
@@ -268,8 +827,9 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a PathBuf) -> Option<F
 
         let new_level = if func_space {
             let state = State {
-                space: FuncSpace::new::<T::Getter>(&node, code, kind),
+                space: FuncSpace::new::<T::Getter>(&node, code, kind, options, selection),
                 halstead_maps: HalsteadMaps::new(),
+                custom: options.custom.new_stats(),
             };
             state_stack.push(state);
             last_level = level + 1;
@@ -280,13 +840,47 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a PathBuf) -> Option<F
 
         if let Some(state) = state_stack.last_mut() {
             let last = &mut state.space;
-            T::Cognitive::compute(&node, &mut last.metrics.cognitive);
-            T::Cyclomatic::compute(&node, &mut last.metrics.cyclomatic);
-            T::Halstead::compute(&node, code, &mut state.halstead_maps);
-            T::Loc::compute(&node, &mut last.metrics.loc, func_space, unit);
-            T::Nom::compute(&node, &mut last.metrics.nom);
-            T::NArgs::compute(&node, &mut last.metrics.nargs);
-            T::Exit::compute(&node, &mut last.metrics.nexits);
+            // The calls are collected whatever the selected metrics,
+            // since they are needed to build the call graph
+            if T::Checker::is_call(&node) {
+                if let Some((name, receiver)) = T::Getter::get_call(&node, code) {
+                    last.calls.push(Call::new(&node, name, receiver));
+                    if computed.contains(Metric::Cognitive) {
+                        last.metrics.cognitive.add_call(&node);
+                    }
+                }
+            }
+            if computed.contains(Metric::Cognitive) {
+                T::Cognitive::compute(&node, &mut last.metrics.cognitive);
+            }
+            if computed.contains(Metric::Cyclomatic) {
+                T::Cyclomatic::compute(&node, &mut last.metrics.cyclomatic);
+            }
+            if computed.contains(Metric::Halstead) {
+                T::Halstead::compute(&node, code, &mut state.halstead_maps);
+            }
+            if computed.contains(Metric::Loc) {
+                T::Loc::compute(&node, &mut last.metrics.loc, func_space, unit);
+            }
+            if computed.contains(Metric::Nom) {
+                T::Nom::compute(&node, &mut last.metrics.nom);
+            }
+            if computed.contains(Metric::NArgs) {
+                T::NArgs::compute(&node, &mut last.metrics.nargs);
+            }
+            if computed.contains(Metric::NExits) {
+                T::Exit::compute(&node, &mut last.metrics.nexits);
+            }
+            if computed.contains(Metric::Ck) {
+                T::Ck::compute(&node, code, &mut last.metrics.ck);
+            }
+            options
+                .custom
+                .compute(&node, code, &state.space, &mut state.custom);
+            if node.is_error() || node.is_missing() {
+                state.space.errors.push(ParseError::new(&node));
+                state.space.parse_errors += 1;
+            }
         }
 
         cursor.reset(node.object());
@@ -303,19 +897,44 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a PathBuf) -> Option<F
         }
     }
 
-    finalize::<T>(&mut state_stack, std::usize::MAX);
+    finalize::<T>(&mut state_stack, std::usize::MAX, &options.custom);
 
     state_stack.pop().map(|mut state| {
+        options.custom.finalize(&mut state.space, &mut state.custom);
+        if computed.contains(Metric::Ck) {
+            ck::compute_inheritance(&mut state.space);
+        }
+        // The recursive functions are detected whatever the selected metrics,
+        // while they increase the cognitive complexity only when it is computed
+        cognitive::compute_recursion(&mut state.space, computed.contains(Metric::Cognitive));
         state.space.name = path.to_str().map(|name| name.to_string());
         state.space
     })
 }
 
+/// Options which change the way the metrics of a code are computed.
+#[derive(Debug, Default, Clone)]
+pub struct MetricsOptions {
+    /// Records the node, the position, and the nesting level
+    /// of every increment of the `Cognitive Complexity` metric
+    pub cognitive_increments: bool,
+    /// The custom metrics computed along with the built-in ones
+    pub custom: CustomMetrics,
+    /// The built-in metrics to compute, or all of them if `None`
+    ///
+    /// The metrics needed to compute the selected ones are computed too,
+    /// e.g. the lines of code for the maintainability index,
+    /// but they are not serialized.
+    pub metrics: Option<Vec<Metric>>,
+}
+
 /// Configuration options for computing
 /// the metrics of a code.
 pub struct MetricsCfg {
     /// Path to the file containing the code
     pub path: PathBuf,
+    /// Options used to compute the metrics
+    pub options: MetricsOptions,
 }
 
 pub struct Metrics {
@@ -327,10 +946,195 @@ impl Callback for Metrics {
     type Cfg = MetricsCfg;
 
     fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
-        if let Some(space) = metrics(parser, &cfg.path) {
+        if let Some(space) = metrics_with_options(parser, &cfg.path, &cfg.options) {
             dump_root(&space)
         } else {
             Ok(())
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    use super::*;
+
+    use crate::*;
+
+    fn selected(metrics: Option<Vec<Metric>>) -> MetricsOptions {
+        MetricsOptions {
+            metrics,
+            ..MetricsOptions::default()
+        }
+    }
+
+    fn keys(metrics: &CodeMetrics) -> Vec<String> {
+        let value = serde_json::to_value(metrics).unwrap();
+        value.as_object().unwrap().keys().cloned().collect()
+    }
+
+    #[test]
+    fn metric_names() {
+        for metric in Metric::all() {
+            assert_eq!(metric.to_string().parse::<Metric>(), Ok(*metric));
+            assert_eq!(json!(metric), json!(metric.to_string()));
+        }
+        assert!("sloc".parse::<Metric>().is_err());
+    }
+
+    #[test]
+    fn selected_metrics() {
+        let source = "def foo(a):\n    if a:\n        return 1\n";
+        let space = python_space(
+            source,
+            &selected(Some(vec![Metric::Loc, Metric::Cyclomatic])),
+        );
+        let foo = &space.spaces[0];
+
+        assert_eq!(keys(&space.metrics), ["cyclomatic", "loc"]);
+        assert_eq!(keys(&foo.metrics), ["cyclomatic", "loc"]);
+        assert!(foo.metrics.is_selected(Metric::Loc));
+        assert!(!foo.metrics.is_selected(Metric::Halstead));
+        assert_eq!(foo.metrics.cyclomatic.cyclomatic(), 2.);
+        assert_eq!(foo.metrics.loc.sloc(), 3.);
+
+        // The unselected metrics are not computed
+        assert_eq!(foo.metrics.nom.functions(), 0.);
+        assert_eq!(foo.metrics.halstead.operators(), 0.);
+        assert_eq!(foo.metrics.nargs.fn_args(), 0.);
+    }
+
+    #[test]
+    fn selected_metrics_dependencies() {
+        let source = "def foo(a):\n    if a:\n        return 1\n";
+        let all = python_space(source, &selected(None));
+        let space = python_space(source, &selected(Some(vec![Metric::Mi, Metric::NArgs])));
+        let (foo, all_foo) = (&space.spaces[0], &all.spaces[0]);
+
+        // The metrics needed by the selected ones are computed,
+        // but they are not serialized
+        assert_eq!(keys(&foo.metrics), ["mi", "nargs"]);
+        assert_eq!(
+            foo.metrics.mi.mi_original(),
+            all_foo.metrics.mi.mi_original()
+        );
+        assert_eq!(foo.metrics.nargs.fn_args(), 1.);
+        assert_eq!(
+            foo.metrics.nargs.fn_args_average(),
+            all_foo.metrics.nargs.fn_args_average()
+        );
+    }
+
+    #[test]
+    fn deserialized_selected_metrics() {
+        let space = python_space("def foo():\n    pass\n", &selected(Some(vec![Metric::Nom])));
+        let value = serde_json::to_value(&space).unwrap();
+        let deserialized: FuncSpace = serde_json::from_value(value.clone()).unwrap();
+
+        assert!(deserialized.metrics.is_selected(Metric::Nom));
+        assert!(!deserialized.metrics.is_selected(Metric::Loc));
+        assert_eq!(serde_json::to_value(&deserialized).unwrap(), value);
+    }
+
+    #[test]
+    fn flat_values() {
+        let space = python_space(
+            "def foo(a):\n    return a\n",
+            &selected(Some(vec![Metric::Cyclomatic, Metric::NArgs])),
+        );
+        let values = space.spaces[0].metrics.flat_values();
+        let names: Vec<_> = values.iter().map(|(name, _)| name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            [
+                "cyclomatic.average",
+                "cyclomatic.sum",
+                "nargs.average",
+                "nargs.closures",
+                "nargs.closures_average",
+                "nargs.functions",
+                "nargs.functions_average",
+                "nargs.sum"
+            ]
+        );
+        assert_eq!(values[1].1, 1.);
+        // A function without closures has no closure arguments average
+        assert!(values[4].1.is_nan());
+    }
+
+    #[test]
+    fn try_metrics_parse_errors() {
+        let path = PathBuf::from("foo.py");
+
+        let parser = PythonParser::new(b"def foo():\n    pass\n".to_vec(), &path, None);
+        assert!(try_metrics(&parser, &path).is_ok());
+
+        // An unclosed parenthesis and a missing colon
+        let parser = PythonParser::new(
+            b"def foo(:\n    pass\ndef bar()\n    pass\n".to_vec(),
+            &path,
+            None,
+        );
+        match try_metrics(&parser, &path) {
+            Err(Error::Parse { errors }) => assert!(errors >= 2),
+            res => panic!("Unexpected result: {:?}", res.map(|_| ())),
+        }
+
+        // The metrics are still computed by the lenient function
+        assert!(metrics(&parser, &path).is_some());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let path = PathBuf::from("foo.java");
+        let source = "class A {\n  void f() {\n    int a = 1\n  }\n  void g() {}\n}\n";
+        let parser = JavaParser::new(source.as_bytes().to_vec(), &path, None);
+        let space = metrics(&parser, &path).unwrap();
+        let class = &space.spaces[0];
+        let (f, g) = (&class.spaces[0], &class.spaces[1]);
+
+        // The count includes the errors of the subspaces,
+        // while the locations are only the ones inside the space
+        assert_eq!(
+            (space.parse_errors, class.parse_errors, f.parse_errors),
+            (1, 1, 1)
+        );
+        assert_eq!(g.parse_errors, 0);
+        assert!(space.errors.is_empty() && class.errors.is_empty());
+        assert_eq!(
+            f.errors,
+            [ParseError {
+                missing: true,
+                kind: ";".to_string(),
+                start_line: 3,
+                start_column: 14,
+                end_line: 3,
+                end_column: 14,
+            }]
+        );
+        assert_eq!(f.errors[0].to_string(), "missing `;` at 3:14-3:14");
+
+        // The parse errors are serialized only when there are some
+        let value = serde_json::to_value(&space).unwrap();
+        assert_eq!(value["parse_errors"], 1);
+        assert!(value["spaces"][0]["spaces"][1].get("errors").is_none());
+        let deserialized: FuncSpace = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized.spaces[0].spaces[0].errors, f.errors);
+
+        let space = python_space(
+            "def foo():\n    pass\n\ndef bar(a):\n    if a\n        return 1\n    return a = 2\n",
+            &selected(None),
+        );
+        let (foo, bar) = (&space.spaces[0], &space.spaces[1]);
+        assert_eq!((space.parse_errors, foo.parse_errors), (2, 0));
+        // The end columns are exclusive
+        let errors: Vec<_> = bar.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            ["syntax error at 5:5-5:9", "syntax error at 7:12-7:15"]
+        );
+    }
+}
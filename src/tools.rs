@@ -1,3 +1,4 @@
+use crate::error::Error;
 use crate::langs::fake;
 use crate::langs::*;
 use regex::bytes::Regex;
@@ -31,6 +32,11 @@ pub fn read_file(path: &PathBuf) -> std::io::Result<Vec<u8>> {
 
 /// Reads a file and adds an `EOL` at its end.
 ///
+/// `None` is returned when the file is almost empty or does not look like
+/// text, see [`try_read_file_with_eol`] to know the reason.
+///
+/// [`try_read_file_with_eol`]: fn.try_read_file_with_eol.html
+///
 /// # Examples
 ///
 /// ```
@@ -42,45 +48,140 @@ pub fn read_file(path: &PathBuf) -> std::io::Result<Vec<u8>> {
 /// read_file_with_eol(&path).unwrap();
 /// ```
 pub fn read_file_with_eol(path: &PathBuf) -> std::io::Result<Option<Vec<u8>>> {
+    optional_code(try_read_file_with_eol(path))
+}
+
+/// Reads a file and adds an `EOL` at its end.
+///
+/// Returns an [`Error`] when the file cannot be read,
+/// when it is almost empty or when it does not look like text.
+///
+/// [`Error`]: enum.Error.html
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_code_analysis::{try_read_file_with_eol, Error};
+///
+/// let path = PathBuf::from("Cargo.toml");
+/// try_read_file_with_eol(&path).unwrap();
+///
+/// let path = PathBuf::from("foo.rs");
+/// assert!(matches!(try_read_file_with_eol(&path), Err(Error::Io(_))));
+/// ```
+pub fn try_read_file_with_eol(path: &PathBuf) -> Result<Vec<u8>, Error> {
     let file_size = fs::metadata(&path).map_or(1024 * 1024, |m| m.len() as usize);
     if file_size <= 3 {
         // this file is very likely almost empty... so nothing to do on it
-        return Ok(None);
+        return Err(Error::EmptyCode);
     }
 
     let mut file = File::open(path)?;
 
     let mut start = vec![0; 64.min(file_size)];
-    let start = if file.read_exact(&mut start).is_ok() {
-        // Skip the bom if one
-        if start[..2] == [b'\xFE', b'\xFF'] || start[..2] == [b'\xFF', b'\xFE'] {
-            &start[2..]
-        } else if start[..3] == [b'\xEF', b'\xBB', b'\xBF'] {
-            &start[3..]
-        } else {
-            &start
-        }
+    file.read_exact(&mut start)?;
+    let start = text_start(&start).ok_or(Error::NotUtf8)?;
+
+    let mut data = Vec::with_capacity(file_size + 2);
+    data.extend_from_slice(&start);
+
+    file.read_to_end(&mut data)?;
+
+    remove_blank_lines(&mut data);
+
+    Ok(data)
+}
+
+/// Reads a code from a reader, e.g. the standard input,
+/// and adds an `EOL` at its end.
+///
+/// As for [`read_file_with_eol`], `None` is returned when the code
+/// is almost empty or does not look like text.
+///
+/// [`read_file_with_eol`]: fn.read_file_with_eol.html
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_analysis::read_with_eol;
+///
+/// let source = read_with_eol(&mut "int a = 42;".as_bytes()).unwrap();
+/// assert_eq!(source, Some(b"int a = 42;\n".to_vec()));
+/// ```
+pub fn read_with_eol<R: Read>(reader: &mut R) -> std::io::Result<Option<Vec<u8>>> {
+    optional_code(try_read_with_eol(reader))
+}
+
+/// Reads a code from a reader, e.g. the standard input,
+/// and adds an `EOL` at its end.
+///
+/// As for [`try_read_file_with_eol`], an [`Error`] is returned when the code
+/// cannot be read, when it is almost empty or when it does not look like text.
+///
+/// [`try_read_file_with_eol`]: fn.try_read_file_with_eol.html
+/// [`Error`]: enum.Error.html
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_analysis::{try_read_with_eol, Error};
+///
+/// let source = try_read_with_eol(&mut "int a = 42;".as_bytes()).unwrap();
+/// assert_eq!(source, b"int a = 42;\n".to_vec());
+///
+/// let source = try_read_with_eol(&mut "\n".as_bytes());
+/// assert!(matches!(source, Err(Error::EmptyCode)));
+/// ```
+pub fn try_read_with_eol<R: Read>(reader: &mut R) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    if data.len() <= 3 {
+        return Err(Error::EmptyCode);
+    }
+
+    let start = text_start(&data[..64.min(data.len())]).ok_or(Error::NotUtf8)?;
+    let bom = 64.min(data.len()) - start.len();
+    data.drain(..bom);
+
+    remove_blank_lines(&mut data);
+
+    Ok(data)
+}
+
+// Converts the result of reading a code, the errors which are not
+// input/output errors meaning that there is nothing to analyze
+fn optional_code(result: Result<Vec<u8>, Error>) -> std::io::Result<Option<Vec<u8>>> {
+    match result {
+        Ok(data) => Ok(Some(data)),
+        Err(Error::Io(e)) => Err(e),
+        Err(_) => Ok(None),
+    }
+}
+
+// Returns the start of a code without its bom,
+// or None if it contains some invalid chars
+fn text_start(start: &[u8]) -> Option<&[u8]> {
+    // Skip the bom if one
+    let start = if start[..2] == [b'\xFE', b'\xFF'] || start[..2] == [b'\xFF', b'\xFE'] {
+        &start[2..]
+    } else if start[..3] == [b'\xEF', b'\xBB', b'\xBF'] {
+        &start[3..]
     } else {
-        return Ok(None);
+        start
     };
 
     // so start contains more or less 64 chars
-    let mut head = String::from_utf8_lossy(&start).into_owned();
+    let mut head = String::from_utf8_lossy(start).into_owned();
     // The last char could be wrong because we were in the middle of an utf-8 sequence
     head.pop();
     // now check if there is an invalid char
     if head.contains('\u{FFFD}') {
-        return Ok(None);
+        None
+    } else {
+        Some(start)
     }
-
-    let mut data = Vec::with_capacity(file_size + 2);
-    data.extend_from_slice(&start);
-
-    file.read_to_end(&mut data)?;
-
-    remove_blank_lines(&mut data);
-
-    Ok(Some(data))
 }
 
 /// Writes data to a file.
@@ -349,6 +450,19 @@ pub(crate) fn guess_file<S: ::std::hash::BuildHasher>(
     vec![]
 }
 
+/// Computes the metrics of a Python code, as if it was read from `foo.py`.
+#[cfg(test)]
+pub(crate) fn python_space(
+    source: &str,
+    options: &crate::spaces::MetricsOptions,
+) -> crate::spaces::FuncSpace {
+    use crate::traits::ParserTrait;
+
+    let path = PathBuf::from("foo.py");
+    let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+    crate::spaces::metrics_with_options(&parser, &path, options).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -372,9 +486,32 @@ mod tests {
             write_file(&tmp_path, &d).unwrap();
             let res = read_file_with_eol(&tmp_path).unwrap();
             assert_eq!(res, expected);
+            let res = read_with_eol(&mut d.as_slice()).unwrap();
+            assert_eq!(res, expected);
         }
     }
 
+    #[test]
+    fn test_try_read() {
+        let tmp_dir = std::env::temp_dir();
+        let tmp_path = tmp_dir.join("test_try_read");
+        let data = vec![
+            (b"ab\n".to_vec(), Error::EmptyCode),
+            (b"\xEF\xBBabc\n".to_vec(), Error::NotUtf8),
+            (b"abc\xFF\xFEdef\n".to_vec(), Error::NotUtf8),
+        ];
+        for (d, expected) in data {
+            write_file(&tmp_path, &d).unwrap();
+            let res = try_read_file_with_eol(&tmp_path).unwrap_err();
+            assert_eq!(res.to_string(), expected.to_string());
+            let res = try_read_with_eol(&mut d.as_slice()).unwrap_err();
+            assert_eq!(res.to_string(), expected.to_string());
+        }
+
+        let res = try_read_file_with_eol(&tmp_dir.join("test_try_read_missing"));
+        assert!(matches!(res, Err(Error::Io(_))));
+    }
+
     #[test]
     fn test_guess_language() {
         let buf = b"// -*- foo: bar; mode: c++; hello: world\n";
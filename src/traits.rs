@@ -4,6 +4,7 @@ use tree_sitter::Language;
 
 use crate::alterator::Alterator;
 use crate::checker::Checker;
+use crate::ck::Ck;
 use crate::cognitive::Cognitive;
 use crate::cyclomatic::Cyclomatic;
 use crate::exit::Exit;
@@ -33,7 +34,10 @@ pub trait Callback {
 }
 
 #[doc(hidden)]
-pub trait CodeMetricsT: Cognitive + Cyclomatic + Exit + Halstead + NArgs + Loc + Nom + Mi {}
+pub trait CodeMetricsT:
+    Cognitive + Cyclomatic + Exit + Halstead + NArgs + Loc + Nom + Mi + Ck
+{
+}
 
 #[doc(hidden)]
 pub trait TSLanguage {
@@ -56,6 +60,7 @@ pub trait ParserTrait {
     type Mi: Mi;
     type NArgs: NArgs;
     type Exit: Exit;
+    type Ck: Ck;
 
     fn new(code: Vec<u8>, path: &PathBuf, pr: Option<Arc<PreprocResults>>) -> Self;
     fn get_language(&self) -> LANG;
@@ -64,9 +69,21 @@ pub trait ParserTrait {
     fn get_filters(&self, filters: &[String]) -> Filter;
 }
 
-pub(crate) trait Search<'a> {
+/// A trait to search the nodes of an `AST`.
+///
+/// The predicates are called with the kind identifiers of the nodes,
+/// see [`Node::kind_id`].
+///
+/// [`Node::kind_id`]: struct.Node.html#method.kind_id
+pub trait Search<'a> {
+    /// Returns the first node, in depth-first order, whose kind satisfies
+    /// the predicate, starting from the node itself
     fn first_occurence(&self, pred: fn(u16) -> bool) -> Option<Node<'a>>;
+    /// Calls an action on the node and on all its descendants,
+    /// in depth-first order
     fn act_on_node(&self, pred: &mut dyn FnMut(&Node<'a>));
+    /// Returns the first child whose kind satisfies the predicate
     fn first_child(&self, pred: fn(u16) -> bool) -> Option<Node<'a>>;
+    /// Calls an action on each child of the node
     fn act_on_child(&self, action: &mut dyn FnMut(&Node<'a>));
 }